@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+/// The answer to a single puzzle part, whatever type the solution returned.
+///
+/// Integers are kept in `Int` whenever they fit in an `i64`, so two answers
+/// holding the same number always compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "RawAnswer", into = "RawAnswer")]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Text(String),
+    /// Multi-line ASCII art, e.g. a CRT screen.
+    Art(String),
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Art(_))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(v) => write!(f, "{}", v),
+            Answer::BigInt(v) => write!(f, "{}", v),
+            Answer::Text(s) | Answer::Art(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        match i64::try_from(value) {
+            Ok(v) => Answer::Int(v),
+            Err(_) => Answer::BigInt(value),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::from(value as i128)
+            }
+        })*
+    };
+}
+
+answer_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        if value.contains('\n') {
+            Answer::Art(value)
+        } else {
+            Answer::Text(value)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::from(value.to_string())
+    }
+}
+
+/// Plain serialized form: integers that fit in an `i64` are numbers,
+/// everything else is a string.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawAnswer {
+    Int(i64),
+    Text(String),
+}
+
+impl From<RawAnswer> for Answer {
+    fn from(raw: RawAnswer) -> Self {
+        match raw {
+            RawAnswer::Int(v) => Answer::Int(v),
+            RawAnswer::Text(s) => match s.parse::<i128>() {
+                Ok(v) if i64::try_from(v).is_err() => Answer::BigInt(v),
+                _ => Answer::from(s),
+            },
+        }
+    }
+}
+
+impl From<Answer> for RawAnswer {
+    fn from(answer: Answer) -> Self {
+        match answer {
+            Answer::Int(v) => RawAnswer::Int(v),
+            other => RawAnswer::Text(other.to_string()),
+        }
+    }
+}
+
+/// Converts the return value of a `solve_*` function into an [`Answer`].
+pub trait IntoAnswer {
+    fn into_answer(self) -> anyhow::Result<Answer>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for Option<T> {
+    fn into_answer(self) -> anyhow::Result<Answer> {
+        self.map(Into::into)
+            .ok_or_else(|| anyhow::anyhow!("No answer"))
+    }
+}
+
+impl<T: Into<Answer>, E: Display> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> anyhow::Result<Answer> {
+        self.map(Into::into).map_err(|e| anyhow::anyhow!("{}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers_normalize() {
+        assert_eq!(Answer::from(42usize), Answer::from(42i32));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128));
+        assert_eq!(Answer::from(-7isize), Answer::Int(-7));
+    }
+
+    #[test]
+    fn test_strings() {
+        assert_eq!(Answer::from("CMZ"), Answer::Text("CMZ".to_string()));
+        assert!(Answer::from("#..#\n.##.").is_multiline());
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let answers = vec![
+            Answer::Int(240320250),
+            Answer::BigInt(u64::MAX as i128 + 1),
+            Answer::Text("MCD".to_string()),
+            Answer::Art("##\n##".to_string()),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(
+            json,
+            r###"[240320250,"18446744073709551616","MCD","##\n##"]"###
+        );
+        let back: Vec<Answer> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, answers);
+    }
+
+    #[test]
+    fn test_into_answer() {
+        assert_eq!(Some(5usize).into_answer().unwrap(), Answer::Int(5));
+        assert!(None::<usize>.into_answer().is_err());
+        let err: Result<i64, String> = Err("bad input".to_string());
+        assert_eq!(err.into_answer().unwrap_err().to_string(), "bad input");
+    }
+}
@@ -0,0 +1,306 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use crate::host::Host;
+use crate::report::{Record, PARSE};
+
+/// Human readable duration, e.g. `81.44 µs`.
+pub fn format_time(ns: f64) -> String {
+    if ns < 1000.0 {
+        format!("{:.2} ns", ns)
+    } else if ns < 1_000_000.0 {
+        format!("{:.2} µs", ns / 1000.0)
+    } else if ns < 1_000_000_000.0 {
+        format!("{:.2} ms", ns / 1_000_000.0)
+    } else {
+        format!("{:.2} s", ns / 1_000_000_000.0)
+    }
+}
+
+/// Benchmark times of a day. `parse` is only set for days that time it
+/// separately, otherwise it is part of both parts' times.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DayTimes {
+    pub year: u16,
+    pub day: u16,
+    pub parse: Option<f64>,
+    pub part1: Option<f64>,
+    pub part2: Option<f64>,
+}
+
+impl DayTimes {
+    pub fn total(&self) -> f64 {
+        self.parse.unwrap_or(0.0) + self.part1.unwrap_or(0.0) + self.part2.unwrap_or(0.0)
+    }
+}
+
+/// Days with a time for at least one part, newest year first, then by day.
+pub fn day_times(records: &[Record]) -> Vec<DayTimes> {
+    let mut days: BTreeMap<(u16, u16), DayTimes> = BTreeMap::new();
+    for record in records {
+        let Some(ns) = record.time_ns else {
+            continue;
+        };
+        let times = days.entry((record.year, record.day)).or_insert(DayTimes {
+            year: record.year,
+            day: record.day,
+            parse: None,
+            part1: None,
+            part2: None,
+        });
+        match record.part {
+            PARSE => times.parse = Some(ns),
+            1 => times.part1 = Some(ns),
+            _ => times.part2 = Some(ns),
+        }
+    }
+    let mut days: Vec<DayTimes> = days.into_values().collect();
+    days.sort_by_key(|d| (std::cmp::Reverse(d.year), d.day));
+    days
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Click a column header to sort by it, click again to reverse. Cells sort
+/// on their `data-sort` value, empty ones last.
+const SORT_SCRIPT: &str = r#"<script>
+document.querySelectorAll("table").forEach(table => {
+  table.querySelectorAll("th").forEach((th, column) => {
+    th.addEventListener("click", () => {
+      const body = table.tBodies[0];
+      const descending = th.dataset.order === "asc";
+      table.querySelectorAll("th").forEach(h => delete h.dataset.order);
+      th.dataset.order = descending ? "desc" : "asc";
+      const key = row => row.cells[column].dataset.sort;
+      [...body.rows]
+        .sort((a, b) => {
+          if (key(a) === "") return 1;
+          if (key(b) === "") return -1;
+          const order = Number(key(a)) - Number(key(b));
+          return descending ? -order : order;
+        })
+        .forEach(row => body.appendChild(row));
+    });
+  });
+});
+</script>"#;
+
+/// Standalone HTML page with a sortable table per year.
+pub fn html(records: &[Record], host: &Host) -> String {
+    let mut years: BTreeMap<u16, Vec<DayTimes>> = BTreeMap::new();
+    for times in day_times(records) {
+        years.entry(times.year).or_default().push(times);
+    }
+
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str("<title>Advent of Code benchmarks</title>\n<style>\n");
+    out.push_str("body { font-family: sans-serif; }\n");
+    out.push_str("table { border-collapse: collapse; margin-bottom: 2em; }\n");
+    out.push_str("th, td { padding: 2px 12px; text-align: right; }\n");
+    out.push_str("th { cursor: pointer; border-bottom: 1px solid #888; }\n");
+    out.push_str("th[data-order=asc]::after { content: \" ▲\"; }\n");
+    out.push_str("th[data-order=desc]::after { content: \" ▼\"; }\n");
+    out.push_str("</style>\n</head>\n<body>\n");
+    let _ = writeln!(out, "<h1>Advent of Code benchmarks</h1>");
+    let _ = writeln!(
+        out,
+        "<p>{}: {}</p>",
+        escape(&host.cpu),
+        escape(&host.to_string())
+    );
+
+    let cell = |ns: Option<f64>| match ns {
+        Some(ns) => format!("<td data-sort=\"{}\">{}</td>", ns, format_time(ns)),
+        None => "<td data-sort=\"\">-</td>".to_string(),
+    };
+    for (year, days) in years.iter().rev() {
+        let total: f64 = days.iter().map(DayTimes::total).sum();
+        let _ = writeln!(out, "<h2>{}</h2>", year);
+        out.push_str("<table>\n<thead><tr><th>Day</th><th>Parse</th><th>Part 1</th><th>Part 2</th><th>Total</th></tr></thead>\n<tbody>\n");
+        for times in days {
+            let _ = writeln!(
+                out,
+                "<tr><td data-sort=\"{0}\">{0}</td>{1}{2}{3}{4}</tr>",
+                times.day,
+                cell(times.parse),
+                cell(times.part1),
+                cell(times.part2),
+                cell(Some(times.total()))
+            );
+        }
+        let _ = writeln!(
+            out,
+            "</tbody>\n</table>\n<p><strong>Total runtime: {}</strong></p>",
+            format_time(total)
+        );
+    }
+    out.push_str(SORT_SCRIPT);
+    out.push_str("\n</body>\n</html>\n");
+    out
+}
+
+const CHART_WIDTH: f64 = 800.0;
+const LABEL_WIDTH: f64 = 70.0;
+const VALUE_WIDTH: f64 = 80.0;
+const AXIS_HEIGHT: f64 = 30.0;
+const ROW_HEIGHT: f64 = 16.0;
+const BAR_HEIGHT: f64 = 12.0;
+
+/// Horizontal bar chart of the per-day totals on a log scale, one group of
+/// bars per year.
+pub fn svg(records: &[Record]) -> String {
+    let days = day_times(records);
+    let totals: Vec<f64> = days
+        .iter()
+        .map(DayTimes::total)
+        .filter(|&t| t > 0.0)
+        .collect();
+    let min = totals.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = totals.iter().copied().fold(0.0, f64::max);
+    // Whole powers of ten on both ends, with the fastest bar still visible
+    let (low, high) = if totals.is_empty() {
+        (0, 1)
+    } else {
+        let low = min.log10().ceil() as i32 - 1;
+        (low, (max.log10().ceil() as i32).max(low + 1))
+    };
+    let plot_width = CHART_WIDTH - LABEL_WIDTH - VALUE_WIDTH;
+    let x = |ns: f64| LABEL_WIDTH + (ns.log10() - low as f64) / (high - low) as f64 * plot_width;
+
+    let years: BTreeSet<u16> = days.iter().map(|d| d.year).collect();
+    let height = AXIS_HEIGHT + ROW_HEIGHT * (days.len() + years.len()) as f64 + 10.0;
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\" font-family=\"sans-serif\" font-size=\"11\">",
+        CHART_WIDTH, height
+    );
+    let _ = writeln!(
+        out,
+        "<rect width=\"{}\" height=\"{}\" fill=\"white\"/>",
+        CHART_WIDTH, height
+    );
+    for decade in low..=high {
+        let gx = x(10f64.powi(decade));
+        let _ = writeln!(
+            out,
+            "<line x1=\"{0:.1}\" y1=\"{1}\" x2=\"{0:.1}\" y2=\"{2}\" stroke=\"#ddd\"/>",
+            gx,
+            AXIS_HEIGHT - 8.0,
+            height
+        );
+        let _ = writeln!(
+            out,
+            "<text x=\"{:.1}\" y=\"{}\" text-anchor=\"middle\" fill=\"#666\">{}</text>",
+            gx,
+            AXIS_HEIGHT - 12.0,
+            format_time(10f64.powi(decade)).replace(".00", "")
+        );
+    }
+
+    let mut y = AXIS_HEIGHT;
+    let mut year = None;
+    for times in &days {
+        if year != Some(times.year) {
+            year = Some(times.year);
+            let _ = writeln!(
+                out,
+                "<text x=\"4\" y=\"{:.1}\" font-weight=\"bold\">{}</text>",
+                y + BAR_HEIGHT - 2.0,
+                times.year
+            );
+            y += ROW_HEIGHT;
+        }
+        let total = times.total();
+        let _ = writeln!(
+            out,
+            "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">Day {}</text>",
+            LABEL_WIDTH - 6.0,
+            y + BAR_HEIGHT - 2.0,
+            times.day
+        );
+        if total > 0.0 {
+            let width = x(total) - LABEL_WIDTH;
+            let _ = writeln!(
+                out,
+                "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{}\" fill=\"#4c78a8\"><title>{} day {}: {}</title></rect>",
+                LABEL_WIDTH,
+                y,
+                width,
+                BAR_HEIGHT,
+                times.year,
+                times.day,
+                format_time(total)
+            );
+            let _ = writeln!(
+                out,
+                "<text x=\"{:.1}\" y=\"{:.1}\">{}</text>",
+                LABEL_WIDTH + width + 4.0,
+                y + BAR_HEIGHT - 2.0,
+                format_time(total)
+            );
+        }
+        y += ROW_HEIGHT;
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::RecordStatus;
+
+    fn record(year: u16, day: u16, part: u8, ns: f64) -> Record {
+        Record {
+            year,
+            day,
+            part,
+            answer: None,
+            status: RecordStatus::Solved,
+            time_ns: Some(ns),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_day_times() {
+        let records = vec![
+            record(2023, 2, 1, 10.0),
+            record(2025, 1, 2, 5.0),
+            record(2023, 2, 2, 20.0),
+            record(2023, 2, PARSE, 3.0),
+        ];
+        let days = day_times(&records);
+        assert_eq!(
+            days.iter()
+                .map(|d| (d.year, d.day, d.total()))
+                .collect::<Vec<_>>(),
+            vec![(2025, 1, 5.0), (2023, 2, 33.0)]
+        );
+        assert_eq!(days[0].part1, None);
+    }
+
+    #[test]
+    fn test_svg_log_scale() {
+        // 100 ns to 1 ms spans four decades
+        let records = vec![
+            record(2023, 1, 1, 1_000.0),
+            record(2023, 2, 1, 10_000.0),
+            record(2023, 3, 1, 1_000_000.0),
+        ];
+        let chart = svg(&records);
+        let plot_width = CHART_WIDTH - LABEL_WIDTH - VALUE_WIDTH;
+        assert!(chart.contains(&format!("width=\"{:.1}\" height=\"12\"", plot_width / 4.0)));
+        assert!(chart.contains(&format!("width=\"{:.1}\" height=\"12\"", plot_width / 2.0)));
+        assert!(chart.contains(&format!("width=\"{:.1}\" height=\"12\"", plot_width)));
+        assert_eq!(chart.matches("<rect x=").count(), 3);
+    }
+}
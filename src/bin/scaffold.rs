@@ -1,80 +1,16 @@
-use chrono::prelude::*;
+use chrono::NaiveDate;
 use clap::Parser;
 use serde::Serialize;
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    path::Path,
     process,
 };
+use AOC::cli::{is_day, is_year};
+use AOC::scaffold::{self, FileEdit, Param, Puzzle};
 
-const MODULE_TEMPLATE: &str = r###"// Advent of Code {{ year }} - Day {{ day }}
-
-
-pub fn solution_{{ year }}_{{ day }}_01(file_path: String) -> anyhow::Result<usize> {
-    anyhow::bail!("Yet to be implemented")
-}
-
-pub fn solution_{{ year }}_{{ day }}_02(file_path: String) -> anyhow::Result<usize> {
-    anyhow::bail!("Yet to be implemented")
-}
-
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_{{ year}}_{{ day }}_01() {
-        let file_path: String = String::from("inputs/{{ year }}/day{{ day }}e.txt");
-        let result = solution_{{ year }}_{{ day }}_01(file_path).unwrap();
-        assert_eq!(result, 15);
-    }
-
-    #[test]
-    fn test_{{ year}}_{{ day }}_02() {
-        let file_path: String = String::from("inputs/{{ year }}/day{{ day }}e.txt");
-        let result = solution_{{ year }}_{{ day }}_02(file_path).unwrap();
-        assert_eq!(result, 12);
-    }
-
-    #[test]
-    #[ignore]
-    fn output_{{ year}}_{{ day }}_01() {
-        let file_path: String = String::from("inputs/{{ year }}/day{{ day }}.txt");
-        let result = solution_{{ year }}_{{ day }}_01(file_path);
-        dbg!(result.unwrap());
-        assert_eq!(1, 1);
-    }
-
-    #[test]
-    #[ignore]
-    fn output_{{ year}}_{{ day }}_02() {
-        let file_path: String = String::from("inputs/{{ year }}/day{{ day }}.txt");
-        let result = solution_{{ year }}_{{ day }}_02(file_path);
-        dbg!(result.unwrap());
-        assert_eq!(1, 1);
-    }
-}
-"###;
-
-fn is_year(s: &str) -> Result<u16, Box<dyn std::error::Error + Send + Sync + 'static>> {
-    let current_year = chrono::Utc::now().year() as u16;
-    let year: u16 = s.parse()?;
-    if year > 2014 && year <= current_year {
-        Ok(year)
-    } else {
-        Err(format!("{} is not in the range 2015-{}", year, current_year).into())
-    }
-}
-
-fn is_day(s: &str) -> Result<u16, Box<dyn std::error::Error + Send + Sync + 'static>> {
-    let day: u16 = s.parse()?;
-    if day > 0 && day <= 25 {
-        Ok(day)
-    } else {
-        Err(format!("{} is not in the range 1-25", day).into())
-    }
-}
+/// Directory the module templates are loaded from, `<name>.rs.tera` each.
+const TEMPLATE_DIR: &str = "templates";
 
 #[derive(Parser, Debug, Serialize)]
 #[command(author, version, about, long_about = None)]
@@ -83,80 +19,273 @@ struct Cli {
     year: u16,
     #[arg(value_parser = is_day)]
     day: u16,
+    /// Print the planned changes as a diff without writing anything
+    #[arg(long)]
+    dry_run: bool,
+    /// Module template to start from, e.g. `grid`, `graph` or `parser`
+    #[arg(long, default_value = "default")]
+    template: String,
+    /// Puzzle title, for the module header
+    #[arg(long)]
+    title: Option<String>,
+    /// Puzzle page saved from the browser, to take the title, example inputs
+    /// and example answers from
+    #[arg(long)]
+    from_html: Option<String>,
+    /// Number of example inputs to create and test, at least one
+    #[arg(long, default_value_t = 1)]
+    examples: usize,
+    /// Extra argument of the solve functions as `name:type`, or
+    /// `name:type=value` with the value to use for the real input
+    #[arg(long = "param", value_name = "NAME:TYPE[=VALUE]")]
+    params: Vec<Param>,
+}
+
+#[derive(Serialize)]
+struct Example {
+    /// File name in the year's input directory, e.g. `day05e2.txt`
+    file: String,
+    /// Appended to the test names, empty for the first example
+    suffix: String,
+    /// Expected answer literals
+    part1_answer: String,
+    part2_answer: String,
 }
 
 #[derive(Serialize)]
 struct TemplateContext {
     year: u16,
-    day: String,
+    day: u16,
+    day_padded: String,
+    /// Day of the week the puzzle unlocked, e.g. `Monday`
+    weekday: String,
+    title: Option<String>,
+    examples: Vec<Example>,
+    /// Return types of the solve functions, to fit the example answers
+    part1_type: String,
+    part2_type: String,
+    params: Vec<Param>,
+    /// The params' real input values, as call arguments after the input
+    args: String,
 }
 
 impl TemplateContext {
-    fn from_cli(cli: &Cli) -> Self {
+    fn from_cli(cli: &Cli, puzzle: &Puzzle) -> Self {
+        let weekday = NaiveDate::from_ymd_opt(cli.year as i32, 12, cli.day as u32)
+            .map(|date| date.format("%A").to_string())
+            .unwrap_or_default();
+        // Placeholders for whatever the page didn't have
+        let answer = |part: usize, example: usize, placeholder: &'static str| {
+            puzzle
+                .parts
+                .get(part)
+                .filter(|p| p.example == Some(example))
+                .and_then(|p| p.answer.as_deref())
+                .unwrap_or(placeholder)
+        };
+        let count = example_count(cli, puzzle);
+        let examples = (0..count)
+            .map(|i| Example {
+                file: scaffold::example_file_name(cli.day, i),
+                suffix: match i {
+                    0 => String::new(),
+                    _ => format!("_e{}", i + 1),
+                },
+                part1_answer: scaffold::answer_literal(answer(0, i, "15")),
+                part2_answer: scaffold::answer_literal(answer(1, i, "12")),
+            })
+            .collect();
         TemplateContext {
             year: cli.year,
-            day: format!("{:02}", cli.day),
+            day: cli.day,
+            day_padded: format!("{:02}", cli.day),
+            weekday,
+            title: cli.title.clone().or(puzzle.title.clone()),
+            examples,
+            part1_type: scaffold::answer_type((0..count).map(|i| answer(0, i, "15"))).to_string(),
+            part2_type: scaffold::answer_type((0..count).map(|i| answer(1, i, "12"))).to_string(),
+            params: cli.params.clone(),
+            args: cli
+                .params
+                .iter()
+                .map(|p| format!(", {}", p.argument()))
+                .collect(),
         }
     }
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+fn example_count(cli: &Cli, puzzle: &Puzzle) -> usize {
+    cli.examples.max(puzzle.examples.len()).max(1)
+}
+
+fn render_module(name: &str, context: TemplateContext) -> Result<String, String> {
+    let templates = tera::Tera::new(&format!("{}/*.rs.tera", TEMPLATE_DIR))
+        .map_err(|e| format!("Failed to load templates: {:?}", e))?;
+    let file_name = format!("{}.rs.tera", name);
+    if templates.get_template(&file_name).is_err() {
+        let mut available: Vec<&str> = templates
+            .get_template_names()
+            .filter_map(|n| n.strip_suffix(".rs.tera"))
+            .collect();
+        available.sort();
+        return Err(format!(
+            "No template \"{}\" in {}/, available: {}",
+            name,
+            TEMPLATE_DIR,
+            available.join(", ")
+        ));
+    }
+    let context = tera::Context::from_serialize(context).expect("Invalid context");
+    templates
+        .render(&file_name, &context)
+        .map_err(|e| format!("Failed to render template \"{}\": {:?}", name, e))
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 fn main() {
     let cli = Cli::parse();
 
-    let templateContext = TemplateContext::from_cli(&cli);
-    let day_padded = templateContext.day.clone();
+    let puzzle = match &cli.from_html {
+        Some(path) => match fs::read_to_string(path) {
+            Ok(html) => scaffold::parse_puzzle(&html),
+            Err(e) => {
+                eprintln!("Failed to read \"{}\": {}", path, e);
+                process::exit(1);
+            }
+        },
+        None => Puzzle::default(),
+    };
+
+    let template_context = TemplateContext::from_cli(&cli, &puzzle);
+    let day_padded = template_context.day_padded.clone();
 
     let input_path = format!("inputs/{}/day{}.txt", cli.year, day_padded);
-    let example_path = format!("inputs/{}/day{}e.txt", cli.year, day_padded);
     let module_path = format!("src/solutions/year{}/day{}.rs", cli.year, day_padded);
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
-            process::exit(1);
-        }
-    };
-
-    let context = tera::Context::from_serialize(templateContext).expect("Invalid context");
-    let content =
-        tera::Tera::one_off(MODULE_TEMPLATE, &context, false).expect("Failed to compile template");
+    let mut edits = Vec::new();
+    if Path::new(&module_path).exists() {
+        println!(
+            "Module file \"{}\" already exists, leaving it as is",
+            &module_path
+        );
+    } else {
+        let content = match render_module(&cli.template, template_context) {
+            Ok(content) => content,
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        };
+        edits.push(FileEdit {
+            path: module_path,
+            before: None,
+            after: content,
+        });
+    }
 
-    match file.write_all(content.as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+    for (i, example) in puzzle.examples.iter().enumerate() {
+        let path = format!(
+            "inputs/{}/{}",
+            cli.year,
+            scaffold::example_file_name(cli.day, i)
+        );
+        if !Path::new(&path).exists() {
+            edits.push(FileEdit {
+                path,
+                before: None,
+                after: example.clone(),
+            });
         }
+    }
+
+    let args: Vec<String> = cli.params.iter().map(Param::argument).collect();
+    match scaffold::plan_registration(cli.year, cli.day, &args) {
+        Ok(registration) => edits.extend(registration),
         Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
+            eprintln!("Failed to read solution modules: {}", e);
             process::exit(1);
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    let example_paths = (0..example_count(&cli, &puzzle)).map(|i| {
+        format!(
+            "inputs/{}/{}",
+            cli.year,
+            scaffold::example_file_name(cli.day, i)
+        )
+    });
+    let new_inputs: Vec<String> = std::iter::once(input_path)
+        .chain(example_paths)
+        .filter(|path| !Path::new(path).exists() && !edits.iter().any(|e| e.path == *path))
+        .collect();
+
+    if cli.dry_run {
+        for edit in &edits {
+            print!(
+                "{}",
+                scaffold::unified_diff(&edit.path, edit.before.as_deref(), &edit.after, 3)
+            );
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
-            process::exit(1);
+        for path in &new_inputs {
+            println!("Would create empty file \"{}\"", path);
+        }
+        if edits.is_empty() && new_inputs.is_empty() {
+            println!("Nothing to do");
         }
+        return;
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    for edit in &edits {
+        match edit.apply() {
+            Ok(_) if edit.before.is_some() => println!("Updated \"{}\"", &edit.path),
+            Ok(_) => println!("Created \"{}\"", &edit.path),
+            Err(e) => {
+                eprintln!("Failed to write \"{}\": {}", &edit.path, e);
+                process::exit(1);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
-            process::exit(1);
+    }
+
+    for path in &new_inputs {
+        match create_file(path) {
+            Ok(_) => {
+                println!("Created empty file \"{}\"", path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create input file: {}", e);
+                process::exit(1);
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use AOC::scaffold::PuzzlePart;
+
+    #[test]
+    fn test_render_text_answer() {
+        let cli = Cli::parse_from(["scaffold", "2022", "5"]);
+        let puzzle = Puzzle {
+            examples: vec!["    [D]\n[N] [C]\n 1   2\n".to_string()],
+            parts: vec![PuzzlePart {
+                example: Some(0),
+                answer: Some("CMZ".to_string()),
+            }],
+            ..Default::default()
+        };
+        let module = render_module("default", TemplateContext::from_cli(&cli, &puzzle)).unwrap();
+        assert!(module.contains("pub fn solve_2022_05_01(input: &str) -> anyhow::Result<String> {"));
+        assert!(module.contains("        assert_eq!(result, \"CMZ\");"));
+        // The placeholder for the missing part 2 answer stays numeric
+        assert!(module.contains("pub fn solve_2022_05_02(input: &str) -> anyhow::Result<usize> {"));
+        assert!(module.contains("        assert_eq!(result, 12);"));
+    }
+}
@@ -1,19 +1,128 @@
-use serde_json::Value;
-use std::collections::BTreeMap;
-use std::fs;
-use std::path::Path;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::SystemTime;
+use AOC::bench_report::{self, format_time};
+use AOC::history::{self, Estimate, Sample, HISTORY_PATH};
+use AOC::host::Host;
+use AOC::profile::{self, format_bytes, CountingAllocator};
+use AOC::registry;
+use AOC::report::{self, Format, Record, PARSE};
 
+/// Only counts once `--memory` enables it.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser, Debug)]
+#[command(about = "Update the README benchmark tables from criterion results")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<BenchCommand>,
+    /// Write a report instead of updating the README
+    #[arg(long, value_enum, conflicts_with = "compare")]
+    format: Option<ReportFormat>,
+    /// File to write the report to, stdout by default
+    #[arg(long, requires = "format")]
+    out: Option<PathBuf>,
+    /// Compare the results against the last recorded run of a git ref on
+    /// this host instead of recording them, and fail on regressions
+    #[arg(long, value_name = "REF")]
+    compare: Option<String>,
+    /// Slowdown in percent above which `--compare` flags a part
+    #[arg(long, default_value_t = 10.0, requires = "compare")]
+    threshold: f64,
+    /// Run every benchmarked day once more to add its peak memory use to
+    /// the README tables
+    #[arg(long, global = true)]
+    memory: bool,
+}
+
+#[derive(Subcommand, Debug)]
+enum BenchCommand {
+    /// Run the criterion benches, then record and tabulate the results
+    ///
+    /// Benchmarks every year with both a `src/solutions/yearXXXX` module and
+    /// an `inputs/XXXX` directory, one `cargo bench` per year.
+    Bench {
+        /// Only benchmark this year
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only benchmark this day
+        #[arg(long, value_parser = clap::value_parser!(u16).range(1..=25))]
+        day: Option<u16>,
+        /// Run this many years at once. Faster, but the runs disturb each
+        /// other's timings
+        #[arg(long, default_value_t = 1)]
+        jobs: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ReportFormat {
+    /// Page with a sortable table per year
+    Html,
+    Csv,
+    Json,
+    Tsv,
+    /// Bar chart of the per-day totals on a log scale
+    Svg,
+}
+
+/// Median times in nanoseconds by year, day and part, e.g.
+/// `{"2023": {"09": {"Parse": 1.2, "1": 3.4, "2": 5.6}}}`.
 type YearResults = BTreeMap<String, BTreeMap<String, BTreeMap<String, f64>>>;
 
-fn parse_criterion_results(criterion_dir: &Path) -> YearResults {
-    let mut results = BTreeMap::new();
+fn year_results(results: &[Record]) -> YearResults {
+    let mut years = YearResults::new();
+    for record in results {
+        let Some(time_ns) = record.time_ns else {
+            continue;
+        };
+        let part = match record.part {
+            PARSE => "Parse".to_string(),
+            part => part.to_string(),
+        };
+        years
+            .entry(record.year.to_string())
+            .or_default()
+            .entry(format!("{:02}", record.day))
+            .or_default()
+            .insert(part, time_ns);
+    }
+    years
+}
+
+fn write_report<W: Write>(out: &mut W, results: &[Record], format: ReportFormat) -> io::Result<()> {
+    match format {
+        ReportFormat::Html => {
+            out.write_all(bench_report::html(results, &Host::detect()).as_bytes())
+        }
+        ReportFormat::Svg => out.write_all(bench_report::svg(results).as_bytes()),
+        ReportFormat::Csv => report::write(out, results, Format::Csv),
+        ReportFormat::Json => {
+            serde_json::to_writer_pretty(&mut *out, &year_results(results))?;
+            writeln!(out)
+        }
+        ReportFormat::Tsv => report::write(out, results, Format::Tsv),
+    }
+}
+
+/// Every criterion estimate under `criterion_dir`, or with `since` only those
+/// written after it.
+fn parse_criterion_results(criterion_dir: &Path, since: Option<SystemTime>) -> Vec<Estimate> {
+    let mut results = Vec::new();
 
     if !criterion_dir.exists() {
         return results;
     }
 
     // Function to process a directory looking for benchmark results
-    fn process_directory(dir: &Path, results: &mut YearResults) {
+    fn process_directory(dir: &Path, since: Option<SystemTime>, results: &mut Vec<Estimate>) {
         if let Ok(entries) = fs::read_dir(dir) {
             for entry in entries.flatten() {
                 let path = entry.path();
@@ -23,68 +132,271 @@ fn parse_criterion_results(criterion_dir: &Path) -> YearResults {
                     continue;
                 }
 
-                // Parse directory name: "YEAR Day DD Part P"
+                // Parse directory name: "YEAR Day DD Part P", or
+                // "YEAR Day DD Parse" for days that time parsing on its own
                 let parts: Vec<&str> = dir_name.split_whitespace().collect();
-                if parts.len() == 5 && parts[1] == "Day" && parts[3] == "Part" {
-                    let year = parts[0].to_string();
-                    let day = parts[2].to_string();
-                    let part = parts[4].to_string();
+                let part = match parts[..] {
+                    [_, "Day", _, "Part", part] => part.parse().ok(),
+                    [_, "Day", _, "Parse"] => Some(PARSE),
+                    _ => None,
+                };
+                if let Some(part) = part {
+                    let (Ok(year), Ok(day)) = (parts[0].parse(), parts[2].parse()) else {
+                        continue;
+                    };
 
                     // Read the estimates.json file
                     let estimates_file = path.join("new/estimates.json");
-                    if estimates_file.exists() {
-                        if let Ok(data) = fs::read_to_string(&estimates_file) {
-                            if let Ok(json) = serde_json::from_str::<Value>(&data) {
-                                // Get median time in nanoseconds
-                                if let Some(median_ns) = json["median"]["point_estimate"].as_f64() {
-                                    results
-                                        .entry(year)
-                                        .or_insert_with(BTreeMap::new)
-                                        .entry(day)
-                                        .or_insert_with(BTreeMap::new)
-                                        .insert(part, median_ns);
-                                }
-                            }
+                    let modified = fs::metadata(&estimates_file).and_then(|m| m.modified());
+                    if since.is_some_and(|since| modified.is_ok_and(|m| m < since)) {
+                        continue;
+                    }
+                    if let Ok(data) = fs::read_to_string(&estimates_file) {
+                        if let Some(estimate) = Estimate::from_criterion(year, day, part, &data) {
+                            results.push(estimate);
                         }
                     }
                 } else {
                     // Recursively check subdirectories (for grouped benchmarks)
-                    process_directory(&path, results);
+                    process_directory(&path, since, results);
                 }
             }
         }
     }
 
-    process_directory(criterion_dir, &mut results);
+    process_directory(criterion_dir, since, &mut results);
+    results.sort_by_key(|r| (r.year, r.day, r.part));
     results
 }
 
-fn format_time(ns: f64) -> String {
-    if ns < 1000.0 {
-        format!("{:.2} ns", ns)
-    } else if ns < 1_000_000.0 {
-        format!("{:.2} µs", ns / 1000.0)
-    } else if ns < 1_000_000_000.0 {
-        format!("{:.2} ms", ns / 1_000_000.0)
-    } else {
-        format!("{:.2} s", ns / 1_000_000_000.0)
+/// Output of a git command, trimmed.
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Years with both a `src/solutions/yearXXXX` module and an `inputs/XXXX`
+/// directory, newest first.
+fn discover_years(solutions_dir: &Path, inputs_dir: &Path) -> Vec<u16> {
+    let Ok(entries) = fs::read_dir(solutions_dir) else {
+        return Vec::new();
+    };
+    let mut years: Vec<u16> = entries
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let name = entry.file_name();
+            name.to_str()?.strip_prefix("year")?.parse().ok()
+        })
+        .filter(|year: &u16| inputs_dir.join(year.to_string()).is_dir())
+        .collect();
+    years.sort_by(|a, b| b.cmp(a));
+    years
+}
+
+/// Criterion filter selecting a year's benchmarks, or just one day's. Ids
+/// are `<group>/<function>`, e.g. `2023/2023 Day 09 Parse`.
+fn bench_filter(year: u16, day: Option<u16>) -> String {
+    match day {
+        Some(day) => format!("^{0}/{0} Day {1:02} ", year, day),
+        None => format!("^{}/", year),
     }
 }
 
-fn generate_table(year_results: &BTreeMap<String, BTreeMap<String, f64>>) -> (String, f64) {
+/// Runs `cargo bench` once per year, `jobs` at a time. Builds the benches
+/// first so the runs don't race to compile them.
+fn run_benches(years: &[u16], day: Option<u16>, jobs: usize) -> anyhow::Result<()> {
+    let cargo_bench = || {
+        let mut command = Command::new("cargo");
+        command.args(["bench", "--bench", "solutions", "--quiet"]);
+        command
+    };
+    let status = cargo_bench().arg("--no-run").status()?;
+    if !status.success() {
+        anyhow::bail!("Building the benches failed ({})", status);
+    }
+
+    let next = AtomicUsize::new(0);
+    let failed: Vec<u16> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.clamp(1, years.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut failed = Vec::new();
+                    while let Some(&year) = years.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        println!("Benchmarking {}...", year);
+                        let status = cargo_bench()
+                            .args(["--", &bench_filter(year, day)])
+                            .status();
+                        if !status.is_ok_and(|s| s.success()) {
+                            failed.push(year);
+                        }
+                    }
+                    failed
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    });
+    if !failed.is_empty() {
+        anyhow::bail!("Benchmarking {:?} failed", failed);
+    }
+    Ok(())
+}
+
+/// The commit being benchmarked, with `-dirty` appended if tracked files
+/// other than the README and the history have uncommitted changes, so
+/// `--compare` never mistakes such a run for the commit's.
+fn benchmarked_commit() -> String {
+    let Some(head) = git(&["rev-parse", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+    let changes = git(&[
+        "status",
+        "--porcelain",
+        "--untracked-files=no",
+        "--",
+        ".",
+        ":!README.md",
+        ":!benchmarks",
+    ]);
+    match changes {
+        Some(changes) if changes.is_empty() => head,
+        _ => format!("{}-dirty", head),
+    }
+}
+
+fn record_history(estimates: &[Estimate], host: &Host) -> anyhow::Result<()> {
+    let commit = benchmarked_commit();
+    if commit.ends_with("-dirty") {
+        eprintln!(
+            "Warning: Uncommitted changes, recording the results as {}",
+            commit
+        );
+    }
+    let date = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    let samples: Vec<Sample> = estimates
+        .iter()
+        .map(|estimate| Sample {
+            commit: commit.clone(),
+            date: date.clone(),
+            host: host.clone(),
+            estimate: estimate.clone(),
+        })
+        .collect();
+    history::append(HISTORY_PATH, &samples)
+}
+
+/// Prints how `estimates` compare to the last run of `git_ref` and returns
+/// whether any part regressed by more than `threshold` percent.
+fn compare(
+    estimates: &[Estimate],
+    host: &Host,
+    git_ref: &str,
+    threshold: f64,
+) -> anyhow::Result<bool> {
+    let Some(commit) = git(&["rev-parse", "--verify", &format!("{}^{{commit}}", git_ref)]) else {
+        anyhow::bail!("Unknown git ref \"{}\"", git_ref);
+    };
+    let baseline = history::latest(&history::load(HISTORY_PATH)?, &commit, host);
+    if baseline.is_empty() {
+        anyhow::bail!(
+            "No results for {} ({}) on {} in {}",
+            git_ref,
+            &commit[..commit.len().min(10)],
+            host.machine(),
+            HISTORY_PATH
+        );
+    }
+
+    let regressions = history::regressions(&baseline, estimates, threshold / 100.0);
+    for regression in &regressions {
+        let e = &regression.after;
+        println!(
+            "{} day {:02} part {}: {} -> {} (+{:.1}%)",
+            e.year,
+            e.day,
+            e.part,
+            format_time(regression.before.median_ns),
+            format_time(e.median_ns),
+            regression.slowdown() * 100.0
+        );
+    }
+    println!(
+        "{} of {} parts more than {}% slower than {}",
+        regressions.len(),
+        estimates.len(),
+        threshold,
+        git_ref
+    );
+    Ok(!regressions.is_empty())
+}
+
+/// Peak memory per day, the higher of its two parts.
+type Memory = BTreeMap<(u16, u16), u64>;
+
+/// Runs each part of every day in `results` once, one at a time, and
+/// measures its peak memory.
+fn measure_memory(results: &[Record]) -> Memory {
+    let days: BTreeSet<(u16, u16)> = results.iter().map(|r| (r.year, r.day)).collect();
+    let mut memory = Memory::new();
+    profile::enable();
+    for (year, day) in days {
+        for entry in registry::for_day(year, day) {
+            let Ok(input) = fs::read_to_string(entry.input_path()) else {
+                continue;
+            };
+            let (_, usage) = profile::run_input(entry, &input);
+            let peak = memory.entry((year, day)).or_default();
+            *peak = (*peak).max(usage.peak_bytes);
+        }
+    }
+    memory
+}
+
+fn generate_table(year: u16, results: &[Record], memory: Option<&Memory>) -> (String, f64) {
     let mut lines = vec![
-        "| Day | Part 1 | Part 2 | Total |".to_string(),
-        "|-----|--------|--------|-------|".to_string(),
+        "| Day | Parse | Part 1 | Part 2 | Total |".to_string(),
+        "|-----|-------|--------|--------|-------|".to_string(),
     ];
+    if memory.is_some() {
+        for line in &mut lines {
+            line.push_str(if line.starts_with("|-") {
+                "--------|"
+            } else {
+                " Memory |"
+            });
+        }
+    }
 
     let mut year_total = 0.0;
 
-    for (day, parts) in year_results {
-        let part1_ns = parts.get("1").copied().unwrap_or(0.0);
-        let part2_ns = parts.get("2").copied().unwrap_or(0.0);
-        let day_total = part1_ns + part2_ns;
+    // Every registered day gets a row, even without benchmark results
+    let days: BTreeSet<u16> = registry::for_year(year).map(|e| e.day).collect();
+    let times: BTreeMap<(u16, u8), f64> = results
+        .iter()
+        .filter(|r| r.year == year)
+        .filter_map(|r| Some(((r.day, r.part), r.time_ns?)))
+        .collect();
+
+    for day_num in days {
+        // Days without a separate parse time include it in their parts
+        let parse_ns = times.get(&(day_num, PARSE)).copied().unwrap_or(0.0);
+        let part1_ns = times.get(&(day_num, 1)).copied().unwrap_or(0.0);
+        let part2_ns = times.get(&(day_num, 2)).copied().unwrap_or(0.0);
+        let day_total = parse_ns + part1_ns + part2_ns;
         year_total += day_total;
 
+        let parse = if parse_ns > 0.0 {
+            format_time(parse_ns)
+        } else {
+            "-".to_string()
+        };
         let part1 = if part1_ns > 0.0 {
             format_time(part1_ns)
         } else {
@@ -95,68 +407,351 @@ fn generate_table(year_results: &BTreeMap<String, BTreeMap<String, f64>>) -> (St
         } else {
             "-".to_string()
         };
-
-        let day_num: i32 = day.parse().unwrap_or(0);
-        lines.push(format!(
-            "| {} | {} | {} | {} |",
-            day_num,
-            part1,
-            part2,
+        let total = if day_total > 0.0 {
             format_time(day_total)
-        ));
+        } else {
+            "-".to_string()
+        };
+
+        let mut line = format!(
+            "| {} | {} | {} | {} | {} |",
+            day_num, parse, part1, part2, total
+        );
+        if let Some(memory) = memory {
+            let peak = memory
+                .get(&(year, day_num))
+                .map_or("-".to_string(), |&b| format_bytes(b));
+            line.push_str(&format!(" {} |", peak));
+        }
+        lines.push(line);
     }
 
     (lines.join("\n"), year_total)
 }
 
-fn update_readme(results: &YearResults) {
-    let readme_path = "README.md";
-    let content = fs::read_to_string(readme_path).unwrap();
+/// The README subsection with `host`'s tables, headed by its
+/// [`Host::machine`].
+fn host_section(host: &Host, results: &[Record], memory: Option<&Memory>) -> String {
+    let mut section = format!("### {}\n\n{}\n\n", host.machine(), host);
 
-    // Find benchmarks section
-    let benchmark_start = content.find("## Benchmarks").unwrap_or(content.len());
-
-    let before = &content[..benchmark_start];
-
-    // Build new benchmarks section
-    let mut new_section = String::from("## Benchmarks\n\n");
-    new_section.push_str("Run on: Apple M4\n\n");
-
-    // Add tables for each year in reverse order
-    let mut years: Vec<_> = results.keys().collect();
+    // Add tables for each registered year in reverse order
+    let mut years: Vec<u16> = registry::years().collect();
     years.sort_by(|a, b| b.cmp(a));
 
     for year in years {
-        new_section.push_str(&format!("### {}\n\n", year));
-        let (table, total) = generate_table(&results[year]);
-        new_section.push_str(&table);
-        new_section.push_str(&format!(
+        if !results.iter().any(|r| r.year == year) {
+            continue;
+        }
+        section.push_str(&format!("#### {}\n\n", year));
+        let (table, total) = generate_table(year, results, memory);
+        section.push_str(&table);
+        section.push_str(&format!(
             "\n\n**Total runtime: {}**\n\n",
             format_time(total)
         ));
     }
+    section
+}
+
+/// Splits the body of the benchmarks section into `(heading, subsection)`
+/// per host. Older READMEs had a single `Run on: <cpu>` line with the year
+/// tables right below, which is read as that host's subsection.
+fn host_sections(body: &str) -> Vec<(String, String)> {
+    let body = body.trim_start();
+    if let Some(legacy) = body.strip_prefix("Run on: ") {
+        let (cpu, tables) = legacy.split_once('\n').unwrap_or((legacy, ""));
+        let tables = tables.trim_start().replace("### ", "#### ");
+        let section = format!("### {}\n\n{}\n\n", cpu.trim(), tables.trim_end());
+        return vec![(cpu.trim().to_string(), section)];
+    }
+
+    let mut sections: Vec<(String, String)> = Vec::new();
+    for line in body.lines() {
+        if let Some(heading) = line.strip_prefix("### ") {
+            sections.push((heading.trim().to_string(), String::new()));
+        }
+        if let Some((_, section)) = sections.last_mut() {
+            section.push_str(line);
+            section.push('\n');
+        }
+    }
+    for (_, section) in &mut sections {
+        *section = format!("{}\n\n", section.trim_end());
+    }
+    sections
+}
+
+/// The benchmarks section with `host`'s subsection replaced by `section`,
+/// or added after the others if it has none yet.
+fn replace_host_section(body: &str, host: &Host, section: String) -> String {
+    let mut sections = host_sections(body);
+    // Sections used to be headed by the CPU alone, those are taken over
+    let position = sections
+        .iter()
+        .position(|(heading, _)| *heading == host.machine())
+        .or_else(|| {
+            sections
+                .iter()
+                .position(|(heading, _)| *heading == host.cpu)
+        });
+    match position {
+        Some(i) => sections[i] = (host.machine(), section),
+        None => sections.push((host.machine(), section)),
+    }
+
+    let mut new_section = String::from("## Benchmarks\n\n");
+    for (_, section) in sections {
+        new_section.push_str(&section);
+    }
+    new_section
+}
+
+/// Rewrites this host's tables in the README, leaving other hosts' alone.
+fn update_readme(results: &[Record], host: &Host, memory: Option<&Memory>) {
+    let readme_path = "README.md";
+    let content = fs::read_to_string(readme_path).unwrap();
+
+    // Find benchmarks section
+    let benchmark_start = content.find("## Benchmarks").unwrap_or(content.len());
+
+    let before = &content[..benchmark_start];
+    let body = content[benchmark_start..]
+        .strip_prefix("## Benchmarks")
+        .unwrap_or_default();
+
+    let section = host_section(host, results, memory);
+    let new_section = replace_host_section(body, host, section);
 
     // Write back
     fs::write(readme_path, format!("{}{}", before, new_section)).unwrap();
 }
 
 fn main() {
+    let cli = Cli::parse();
     let criterion_dir = Path::new("target/criterion");
 
+    // Only results of a bench run here are new, anything else is already in
+    // the history or from an unknown commit
+    let mut bench_started = None;
+    if let Some(BenchCommand::Bench { year, day, jobs }) = cli.command {
+        let mut years = discover_years(Path::new("src/solutions"), Path::new("inputs"));
+        if let Some(year) = year {
+            if !years.contains(&year) {
+                eprintln!(
+                    "Error: No src/solutions/year{0} module with inputs/{0} to benchmark",
+                    year
+                );
+                process::exit(1);
+            }
+            years = vec![year];
+        }
+        if years.is_empty() {
+            eprintln!("Error: No years with both solutions and inputs to benchmark");
+            process::exit(1);
+        }
+        bench_started = Some(SystemTime::now());
+        if let Err(e) = run_benches(&years, day, jobs) {
+            eprintln!("Error: {:#}", e);
+            process::exit(1);
+        }
+        println!();
+    }
+
     if !criterion_dir.exists() {
         eprintln!("Error: No criterion results found. Run 'cargo bench' first.");
         return;
     }
 
+    if let Some(format) = cli.format {
+        let results: Vec<Record> = parse_criterion_results(criterion_dir, None)
+            .iter()
+            .map(Record::from)
+            .collect();
+        let written = match &cli.out {
+            Some(path) => {
+                File::create(path).and_then(|mut file| write_report(&mut file, &results, format))
+            }
+            None => write_report(&mut io::stdout().lock(), &results, format),
+        };
+        if let Err(e) = written {
+            eprintln!("Error: Failed to write results: {}", e);
+            process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(git_ref) = &cli.compare {
+        let estimates = parse_criterion_results(criterion_dir, None);
+        match compare(&estimates, &Host::detect(), git_ref, cli.threshold) {
+            Ok(false) => return,
+            Ok(true) => process::exit(1),
+            Err(e) => {
+                eprintln!("Error: {:#}", e);
+                process::exit(2);
+            }
+        }
+    }
+
     println!("Parsing criterion results...");
-    let results = parse_criterion_results(criterion_dir);
+    let estimates = parse_criterion_results(criterion_dir, None);
+    let results: Vec<Record> = estimates.iter().map(Record::from).collect();
 
-    println!("Found results for {} years", results.len());
-    for (year, days) in &results {
+    let mut days_per_year: BTreeMap<u16, BTreeSet<u16>> = BTreeMap::new();
+    for record in &results {
+        days_per_year
+            .entry(record.year)
+            .or_default()
+            .insert(record.day);
+    }
+    println!("Found results for {} years", days_per_year.len());
+    for (year, days) in &days_per_year {
         println!("  {}: {} days", year, days.len());
     }
 
-    println!("\nUpdating README.md...");
-    update_readme(&results);
+    let host = Host::detect();
+    println!("\nHost: {} ({})", host.machine(), host);
+    if let Some(started) = bench_started {
+        println!("Recording results in {}...", HISTORY_PATH);
+        let fresh = parse_criterion_results(criterion_dir, Some(started));
+        if let Err(e) = record_history(&fresh, &host) {
+            eprintln!("Error: {:#}", e);
+        }
+    }
+
+    let memory = cli.memory.then(|| {
+        println!("Measuring memory use...");
+        measure_memory(&results)
+    });
+    println!("Updating README.md...");
+    update_readme(&results, &host, memory.as_ref());
     println!("Done!");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn host(cpu: &str, cores: usize) -> Host {
+        Host {
+            cpu: cpu.to_string(),
+            cores,
+            os: "Linux 6.1.0 x86_64".to_string(),
+            rustc: "rustc 1.91.0".to_string(),
+            profile: "bench".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_legacy_host_section() {
+        let body = "\n\nRun on: Apple M4\n\n### 2023\n\n| Day |\n\n### 2022\n\n| Day |\n";
+        assert_eq!(
+            host_sections(body),
+            vec![(
+                "Apple M4".to_string(),
+                "### Apple M4\n\n#### 2023\n\n| Day |\n\n#### 2022\n\n| Day |\n\n".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_host_sections() {
+        let body = "\n\n### Apple M4, 10 cores\n\n#### 2023\n\n| a |\n\n### Ryzen 7, 16 cores\n\n#### 2023\n\n| b |\n";
+        let sections = host_sections(body);
+        let headings: Vec<&str> = sections.iter().map(|(h, _)| h.as_str()).collect();
+        assert_eq!(headings, ["Apple M4, 10 cores", "Ryzen 7, 16 cores"]);
+        assert_eq!(
+            sections[1].1,
+            "### Ryzen 7, 16 cores\n\n#### 2023\n\n| b |\n\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_host_section() {
+        let body = "\n\n### Apple M4, 10 cores\n\n| a |\n\n### Ryzen 7, 16 cores\n\n| b |\n";
+        let replaced = replace_host_section(
+            body,
+            &host("Apple M4", 10),
+            "### Apple M4, 10 cores\n\n| c |\n\n".to_string(),
+        );
+        assert_eq!(
+            replaced,
+            "## Benchmarks\n\n### Apple M4, 10 cores\n\n| c |\n\n### Ryzen 7, 16 cores\n\n| b |\n\n"
+        );
+
+        // Same CPU with another core count is another machine
+        let added = replace_host_section(
+            body,
+            &host("Apple M4", 8),
+            "### Apple M4, 8 cores\n\n| d |\n\n".to_string(),
+        );
+        assert!(added.contains("| a |") && added.ends_with("### Apple M4, 8 cores\n\n| d |\n\n"));
+
+        // A section headed by the CPU alone is taken over
+        let migrated = replace_host_section(
+            "\n\nRun on: Apple M4\n\n### 2023\n\n| a |\n",
+            &host("Apple M4", 10),
+            "### Apple M4, 10 cores\n\n| c |\n\n".to_string(),
+        );
+        assert_eq!(
+            migrated,
+            "## Benchmarks\n\n### Apple M4, 10 cores\n\n| c |\n\n"
+        );
+    }
+
+    #[test]
+    fn test_discover_years() {
+        let root = std::env::temp_dir().join(format!("discover-years-{}", std::process::id()));
+        let (solutions, inputs) = (root.join("solutions"), root.join("inputs"));
+        for dir in ["year2022", "year2023", "year2024", "utils"] {
+            fs::create_dir_all(solutions.join(dir)).unwrap();
+        }
+        fs::write(solutions.join("year2021"), "not a module directory").unwrap();
+        for dir in ["2021", "2022", "2023", "2025"] {
+            fs::create_dir_all(inputs.join(dir)).unwrap();
+        }
+        let years = discover_years(&solutions, &inputs);
+        fs::remove_dir_all(&root).unwrap();
+        // 2024 has no inputs, 2021 and 2025 no module
+        assert_eq!(years, vec![2023, 2022]);
+    }
+
+    #[test]
+    fn test_bench_filter() {
+        let day = regex::Regex::new(&bench_filter(2023, Some(1))).unwrap();
+        assert!(day.is_match("2023/2023 Day 01 Part 1"));
+        assert!(day.is_match("2023/2023 Day 01 Parse"));
+        for id in [
+            "2023/2023 Day 10 Part 1",
+            "2023/2023 Day 19 Part 2",
+            "2023/2023 Day 11 Parse",
+            "2022/2022 Day 01 Part 1",
+        ] {
+            assert!(!day.is_match(id), "{}", id);
+        }
+
+        let year = regex::Regex::new(&bench_filter(2023, None)).unwrap();
+        assert!(year.is_match("2023/2023 Day 25 Part 1"));
+        assert!(!year.is_match("2022/2022 Day 01 Part 1"));
+    }
+
+    #[test]
+    fn test_year_results() {
+        let record = |day, part, time_ns| Record {
+            year: 2023,
+            day,
+            part,
+            answer: None,
+            status: report::RecordStatus::Solved,
+            time_ns,
+            error: None,
+        };
+        let results = [
+            record(9, PARSE, Some(1.5)),
+            record(9, 1, Some(20.0)),
+            record(9, 2, Some(30.0)),
+            record(10, 1, None),
+        ];
+        let json = serde_json::to_string(&year_results(&results)).unwrap();
+        assert_eq!(json, r#"{"2023":{"09":{"1":20.0,"2":30.0,"Parse":1.5}}}"#);
+    }
+}
@@ -0,0 +1,88 @@
+use std::cell::RefCell;
+use std::fmt::{self, Display};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Flag shared between the runner and a running solution. The runner cancels
+/// it once a solution is over its `--timeout` budget.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Error returned by [`check`] once the current solution has been cancelled.
+#[derive(Debug)]
+pub struct Cancelled;
+
+impl Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `token` as the calling thread's current token.
+pub fn with_token<R>(token: CancellationToken, f: impl FnOnce() -> R) -> R {
+    let previous = CURRENT.with(|current| current.replace(Some(token)));
+    let result = f();
+    CURRENT.with(|current| *current.borrow_mut() = previous);
+    result
+}
+
+/// Whether the solution running on this thread has been cancelled.
+///
+/// Solutions can poll this in hot loops to give up early. It is always
+/// `false` without a timeout, and on threads the solution spawned itself
+/// (e.g. rayon workers), since the token is tracked per thread.
+pub fn is_cancelled() -> bool {
+    CURRENT.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    })
+}
+
+/// Like [`is_cancelled`], but as an error for use with `?`.
+pub fn check() -> Result<(), Cancelled> {
+    if is_cancelled() {
+        Err(Cancelled)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check().is_ok());
+
+        let token = CancellationToken::new();
+        with_token(token.clone(), || {
+            assert!(check().is_ok());
+            token.cancel();
+            assert!(check().is_err());
+        });
+        assert!(!is_cancelled());
+    }
+}
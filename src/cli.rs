@@ -0,0 +1,48 @@
+use chrono::prelude::*;
+use std::time::Duration;
+
+pub fn is_year(s: &str) -> Result<u16, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let current_year = chrono::Utc::now().year() as u16;
+    let year: u16 = s.parse()?;
+    if year > 2014 && year <= current_year {
+        Ok(year)
+    } else {
+        Err(format!("{} is not in the range 2015-{}", year, current_year).into())
+    }
+}
+
+pub fn is_day(s: &str) -> Result<u16, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let day: u16 = s.parse()?;
+    if day > 0 && day <= 25 {
+        Ok(day)
+    } else {
+        Err(format!("{} is not in the range 1-25", day).into())
+    }
+}
+
+pub fn is_part(s: &str) -> Result<u8, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let part: u8 = s.parse()?;
+    if part == 1 || part == 2 {
+        Ok(part)
+    } else {
+        Err(format!("{} is not a valid part (1 or 2)", part).into())
+    }
+}
+
+/// Parses durations like `500ms`, `5s`, `1.5m`. A bare number is in seconds.
+pub fn is_duration(
+    s: &str,
+) -> Result<Duration, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (value, unit) = match s.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(i) => s.split_at(i),
+        None => (s, "s"),
+    };
+    let value: f64 = value.parse()?;
+    let seconds = match unit {
+        "ms" => value / 1000.0,
+        "s" => value,
+        "m" => value * 60.0,
+        _ => return Err(format!("unknown unit \"{}\" (use ms, s or m)", unit).into()),
+    };
+    Ok(Duration::try_from_secs_f64(seconds)?)
+}
@@ -0,0 +1,97 @@
+use anyhow::Context;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::str::FromStr;
+
+use crate::answer::Answer;
+
+/// The known answers for one year, as stored in `answers/<year>.toml`:
+///
+/// ```toml
+/// [05]
+/// 1 = 240320250
+/// 2 = 28580589
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, Answer>>);
+
+impl Answers {
+    pub fn path(year: u16) -> String {
+        format!("answers/{}.toml", year)
+    }
+
+    /// Loads the answers for `year`. A year without an answers file has no
+    /// known answers yet, so that is not an error.
+    pub fn load(year: u16) -> anyhow::Result<Answers> {
+        let path = Answers::path(year);
+        match fs::read_to_string(&path) {
+            Ok(content) => content
+                .parse()
+                .with_context(|| format!("Failed to parse \"{}\"", path)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e).with_context(|| format!("Failed to read \"{}\"", path)),
+        }
+    }
+
+    pub fn get(&self, day: u16, part: u8) -> Option<&Answer> {
+        self.0.get(&format!("{:02}", day))?.get(&part.to_string())
+    }
+
+    /// Every `(day, part)` with a known answer.
+    pub fn keys(&self) -> impl Iterator<Item = (u16, u8)> + '_ {
+        self.0.iter().flat_map(|(day, parts)| {
+            parts
+                .keys()
+                .filter_map(move |part| Some((day.parse().ok()?, part.parse().ok()?)))
+        })
+    }
+}
+
+impl FromStr for Answers {
+    type Err = toml::de::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        toml::from_str(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_parse() {
+        let answers: Answers = "[05]\n1 = 35\n2 = 46\n\n[10]\n1 = \"CMZ\"\n"
+            .parse()
+            .unwrap();
+        assert_eq!(answers.get(5, 1), Some(&Answer::Int(35)));
+        assert_eq!(answers.get(5, 2), Some(&Answer::Int(46)));
+        assert_eq!(answers.get(10, 1), Some(&Answer::Text("CMZ".to_string())));
+        assert_eq!(answers.get(10, 2), None);
+        assert_eq!(
+            answers.keys().collect::<Vec<_>>(),
+            [(5, 1), (5, 2), (10, 1)]
+        );
+    }
+
+    #[test]
+    fn test_answer_files_match_registry() {
+        for year in registry::years() {
+            let answers = Answers::load(year).unwrap();
+            for (day, part) in answers.keys() {
+                assert!(
+                    registry::find(year, day, part).is_some(),
+                    "{} has an answer for unregistered {} Day {:02} Part {}",
+                    Answers::path(year),
+                    year,
+                    day,
+                    part
+                );
+            }
+        }
+    }
+}
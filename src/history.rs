@@ -0,0 +1,239 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+
+use crate::host::Host;
+use crate::report::{Record, RecordStatus};
+
+/// Where `update_benchmarks` keeps every run, one [`Sample`] per line.
+pub const HISTORY_PATH: &str = "benchmarks/history.jsonl";
+
+/// Criterion's median estimate for one puzzle part, with its confidence
+/// interval.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Estimate {
+    pub year: u16,
+    pub day: u16,
+    pub part: u8,
+    pub median_ns: f64,
+    pub lower_ns: f64,
+    pub upper_ns: f64,
+}
+
+impl Estimate {
+    /// Reads the median out of a criterion `estimates.json`.
+    pub fn from_criterion(year: u16, day: u16, part: u8, json: &str) -> Option<Estimate> {
+        let json: Value = serde_json::from_str(json).ok()?;
+        let median = &json["median"];
+        let interval = &median["confidence_interval"];
+        Some(Estimate {
+            year,
+            day,
+            part,
+            median_ns: median["point_estimate"].as_f64()?,
+            lower_ns: interval["lower_bound"].as_f64()?,
+            upper_ns: interval["upper_bound"].as_f64()?,
+        })
+    }
+}
+
+impl From<&Estimate> for Record {
+    fn from(estimate: &Estimate) -> Self {
+        Record {
+            year: estimate.year,
+            day: estimate.day,
+            part: estimate.part,
+            answer: None,
+            status: RecordStatus::Solved,
+            time_ns: Some(estimate.median_ns),
+            error: None,
+        }
+    }
+}
+
+/// One line of the history file: an [`Estimate`] and the run it came from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sample {
+    /// Full hash of the commit that was benchmarked.
+    pub commit: String,
+    /// When the run was recorded, RFC 3339.
+    pub date: String,
+    pub host: Host,
+    #[serde(flatten)]
+    pub estimate: Estimate,
+}
+
+/// Loads the history at `path`. A missing file is an empty history.
+pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Vec<Sample>> {
+    let path = path.as_ref();
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {:?}", path)),
+    };
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("Failed to parse {:?} line {}", path, i + 1))
+        })
+        .collect()
+}
+
+/// Appends `samples` to the history at `path`, creating it if needed.
+pub fn append(path: impl AsRef<Path>, samples: &[Sample]) -> anyhow::Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open {:?}", path))?;
+    let mut lines = String::new();
+    for sample in samples {
+        lines.push_str(&serde_json::to_string(sample)?);
+        lines.push('\n');
+    }
+    file.write_all(lines.as_bytes())
+        .with_context(|| format!("Failed to write {:?}", path))
+}
+
+/// The most recent sample per part among those of `commit` on the same kind
+/// of machine as `host`.
+pub fn latest(history: &[Sample], commit: &str, host: &Host) -> Vec<Estimate> {
+    let mut latest: BTreeMap<(u16, u16, u8), &Sample> = BTreeMap::new();
+    for sample in history
+        .iter()
+        .filter(|s| s.commit == commit && s.host.same_machine(host))
+    {
+        let e = &sample.estimate;
+        let entry = latest.entry((e.year, e.day, e.part)).or_insert(sample);
+        if sample.date >= entry.date {
+            *entry = sample;
+        }
+    }
+    latest.values().map(|s| s.estimate.clone()).collect()
+}
+
+/// A part whose median got slower by more than the threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub before: Estimate,
+    pub after: Estimate,
+}
+
+impl Regression {
+    /// How much slower `after` is, e.g. `0.25` for 25%.
+    pub fn slowdown(&self) -> f64 {
+        self.after.median_ns / self.before.median_ns - 1.0
+    }
+}
+
+/// Parts in `after` that are more than `threshold` (a fraction, `0.1` for
+/// 10%) slower than in `before`. Parts missing from either side are skipped.
+pub fn regressions(before: &[Estimate], after: &[Estimate], threshold: f64) -> Vec<Regression> {
+    let before: BTreeMap<_, _> = before
+        .iter()
+        .map(|e| ((e.year, e.day, e.part), e))
+        .collect();
+    after
+        .iter()
+        .filter_map(|a| {
+            let b = before.get(&(a.year, a.day, a.part))?;
+            let regression = Regression {
+                before: (*b).clone(),
+                after: a.clone(),
+            };
+            (regression.slowdown() > threshold).then_some(regression)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn estimate(day: u16, median_ns: f64) -> Estimate {
+        Estimate {
+            year: 2023,
+            day,
+            part: 1,
+            median_ns,
+            lower_ns: median_ns - 10.0,
+            upper_ns: median_ns + 10.0,
+        }
+    }
+
+    #[test]
+    fn test_from_criterion() {
+        let json = r#"{"mean":{"point_estimate":130.0},"median":{"confidence_interval":
+            {"confidence_level":0.95,"lower_bound":110.5,"upper_bound":125.0},
+            "point_estimate":120.0,"standard_error":2.0}}"#;
+        assert_eq!(
+            Estimate::from_criterion(2023, 5, 2, json),
+            Some(Estimate {
+                year: 2023,
+                day: 5,
+                part: 2,
+                median_ns: 120.0,
+                lower_ns: 110.5,
+                upper_ns: 125.0,
+            })
+        );
+    }
+
+    fn host(cpu: &str) -> Host {
+        Host {
+            cpu: cpu.to_string(),
+            cores: 10,
+            os: "Darwin 24.1.0 arm64".to_string(),
+            rustc: "rustc 1.91.0 (f8297e351 2025-10-28)".to_string(),
+            profile: "bench".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_history_roundtrip() {
+        let path = std::env::temp_dir().join(format!("history-{}.jsonl", std::process::id()));
+        let sample = |commit: &str, date: &str, median_ns| Sample {
+            commit: commit.to_string(),
+            date: date.to_string(),
+            host: host("Apple M4"),
+            estimate: estimate(1, median_ns),
+        };
+        let samples = vec![
+            sample("abc", "2025-12-01T10:00:00Z", 100.0),
+            sample("abc", "2025-12-02T10:00:00Z", 90.0),
+            sample("def", "2025-12-03T10:00:00Z", 200.0),
+        ];
+        append(&path, &samples[..1]).unwrap();
+        append(&path, &samples[1..]).unwrap();
+        let history = load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(history, samples);
+        assert_eq!(
+            latest(&history, "abc", &host("Apple M4")),
+            vec![estimate(1, 90.0)]
+        );
+        assert_eq!(latest(&history, "abc", &host("Apple M1")), vec![]);
+    }
+
+    #[test]
+    fn test_regressions() {
+        let before = vec![estimate(1, 100.0), estimate(2, 100.0), estimate(3, 100.0)];
+        let after = vec![estimate(1, 105.0), estimate(2, 150.0), estimate(4, 500.0)];
+        let found = regressions(&before, &after, 0.1);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].after.day, 2);
+        assert!((found[0].slowdown() - 0.5).abs() < 1e-9);
+    }
+}
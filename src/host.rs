@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::fs;
+use std::process::Command;
+use std::thread;
+
+/// The machine and toolchain a benchmark ran on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Host {
+    /// CPU model, e.g. `Apple M4`.
+    pub cpu: String,
+    pub cores: usize,
+    /// Kernel name, release and machine, as from `uname -srm`.
+    pub os: String,
+    /// First line of `rustc -vV`.
+    pub rustc: String,
+    /// Cargo profile the benches were built with, and its settings.
+    pub profile: String,
+}
+
+impl Host {
+    /// Detects the current host. Anything that can't be found out is
+    /// recorded as `unknown`.
+    pub fn detect() -> Host {
+        let cpu = fs::read_to_string("/proc/cpuinfo")
+            .ok()
+            .and_then(|cpuinfo| cpu_model(&cpuinfo))
+            // macOS has no /proc
+            .or_else(|| command("sysctl", &["-n", "machdep.cpu.brand_string"]))
+            .unwrap_or_else(unknown);
+        let cores = thread::available_parallelism().map_or(0, |n| n.get());
+        let os = command("uname", &["-srm"]).unwrap_or_else(unknown);
+        let rustc = command("rustc", &["-vV"])
+            .and_then(|version| version.lines().next().map(String::from))
+            .unwrap_or_else(unknown);
+        let profile = fs::read_to_string("Cargo.toml")
+            .map(|manifest| bench_profile(&manifest))
+            .unwrap_or_else(|_| "bench".to_string());
+        Host {
+            cpu,
+            cores,
+            os,
+            rustc,
+            profile,
+        }
+    }
+
+    /// Whether benchmark times from `self` and `other` are comparable, i.e.
+    /// they ran on the same kind of machine. The toolchain may differ.
+    pub fn same_machine(&self, other: &Host) -> bool {
+        self.machine() == other.machine()
+    }
+
+    /// The parts of the host [`Host::same_machine`] compares, e.g.
+    /// `Apple M4, 10 cores`.
+    pub fn machine(&self) -> String {
+        format!("{}, {} cores", self.cpu, self.cores)
+    }
+}
+
+impl Display for Host {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} cores, {}, {}, {} profile",
+            self.cores, self.os, self.rustc, self.profile
+        )
+    }
+}
+
+fn unknown() -> String {
+    "unknown".to_string()
+}
+
+/// Trimmed stdout of a command that succeeded with some output.
+fn command(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !stdout.is_empty()).then_some(stdout)
+}
+
+/// The CPU model from `/proc/cpuinfo`. x86 has `model name`, ARM boards
+/// usually only `Hardware` or `Model`.
+fn cpu_model(cpuinfo: &str) -> Option<String> {
+    ["model name", "Hardware", "Model"].iter().find_map(|key| {
+        cpuinfo.lines().find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            (name.trim() == *key && !value.trim().is_empty()).then(|| value.trim().to_string())
+        })
+    })
+}
+
+/// `bench` plus whatever `[profile.release]` and `[profile.bench]` in the
+/// manifest change, e.g. `bench (lto = "fat", codegen-units = 1)`.
+fn bench_profile(manifest: &str) -> String {
+    let Ok(manifest) = manifest.parse::<toml::Table>() else {
+        return "bench".to_string();
+    };
+    let mut settings = toml::Table::new();
+    for profile in ["release", "bench"] {
+        if let Some(table) = manifest
+            .get("profile")
+            .and_then(|p| p.get(profile))
+            .and_then(|p| p.as_table())
+        {
+            settings.extend(table.clone());
+        }
+    }
+    if settings.is_empty() {
+        return "bench".to_string();
+    }
+    let settings: Vec<String> = settings
+        .iter()
+        .map(|(key, value)| format!("{} = {}", key, value))
+        .collect();
+    format!("bench ({})", settings.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cpu_model() {
+        let x86 = "processor\t: 0\nvendor_id\t: GenuineIntel\nmodel name\t: Intel(R) Core(TM) i7-8700 CPU @ 3.20GHz\n";
+        assert_eq!(
+            cpu_model(x86).as_deref(),
+            Some("Intel(R) Core(TM) i7-8700 CPU @ 3.20GHz")
+        );
+        let arm = "processor\t: 0\nBogoMIPS\t: 108.00\n\nHardware\t: BCM2835\nModel\t\t: Raspberry Pi 4 Model B Rev 1.4\n";
+        assert_eq!(cpu_model(arm).as_deref(), Some("BCM2835"));
+        assert_eq!(cpu_model("processor\t: 0\n"), None);
+    }
+
+    #[test]
+    fn test_machine() {
+        let host = Host {
+            cpu: "Apple M4".to_string(),
+            cores: 10,
+            os: "Darwin 24.1.0 arm64".to_string(),
+            rustc: "rustc 1.91.0".to_string(),
+            profile: "bench".to_string(),
+        };
+        assert_eq!(host.machine(), "Apple M4, 10 cores");
+        let upgraded = Host {
+            rustc: "rustc 1.92.0".to_string(),
+            ..host.clone()
+        };
+        assert!(host.same_machine(&upgraded));
+        let fewer = Host {
+            cores: 8,
+            ..host.clone()
+        };
+        assert!(!host.same_machine(&fewer));
+    }
+
+    #[test]
+    fn test_bench_profile() {
+        assert_eq!(bench_profile("[package]\nname = \"AOC\"\n"), "bench");
+        assert_eq!(
+            bench_profile("[profile.release]\nlto = \"fat\"\n\n[profile.bench]\ndebug = true\n"),
+            "bench (debug = true, lto = \"fat\")"
+        );
+    }
+}
@@ -1,2 +1,16 @@
+pub mod answer;
+pub mod bench_report;
+pub mod cancel;
+pub mod cli;
+pub mod expected;
+pub mod history;
+pub mod host;
+pub mod profile;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
 pub mod solutions;
 pub mod utils;
+pub mod watch;
@@ -1,3 +1,510 @@
+use clap::{Parser, Subcommand};
+use std::{
+    collections::BTreeMap,
+    fs, io, panic,
+    path::{Path, PathBuf},
+    process, thread,
+    time::{Duration, Instant},
+};
+use AOC::answer::Answer;
+use AOC::cli::{is_day, is_duration, is_part, is_year};
+use AOC::expected::Answers;
+use AOC::profile::{self, format_bytes, CountingAllocator};
+use AOC::registry;
+use AOC::report::{self, Format, Record, RecordStatus};
+use AOC::runner::{self, Outcome, Status};
+use AOC::watch;
+
+/// Only counts once `profile` enables it.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run solutions against their real inputs
+    ///
+    /// With a day, runs that day. Otherwise runs every day of the year (or
+    /// of every year with `--all`) in parallel and prints a summary.
+    Run {
+        #[arg(
+            value_parser = is_year,
+            required_unless_present_any = ["whole_year", "all"],
+            conflicts_with_all = ["whole_year", "all"]
+        )]
+        year: Option<u16>,
+        #[arg(value_parser = is_day)]
+        day: Option<u16>,
+        /// Only run this part (runs both when omitted)
+        #[arg(value_parser = is_part, requires = "day")]
+        part: Option<u8>,
+        /// Run every day of this year
+        #[arg(long = "year", value_name = "YEAR", value_parser = is_year, conflicts_with = "all")]
+        whole_year: Option<u16>,
+        /// Run every day of every year
+        #[arg(long)]
+        all: bool,
+        /// Print machine-readable records instead of a summary
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+        /// Give up on a part after this long, e.g. `500ms` or `5s`
+        #[arg(long, value_parser = is_duration)]
+        timeout: Option<Duration>,
+        /// Read the input from this file instead, e.g. an example input
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+    },
+    /// Rebuild and re-run a day on all its inputs whenever its source or
+    /// inputs change
+    Watch {
+        #[arg(value_parser = is_year)]
+        year: u16,
+        #[arg(value_parser = is_day)]
+        day: u16,
+        /// How often to check for changes
+        #[arg(long, default_value = "500ms", value_parser = is_duration)]
+        interval: Duration,
+        /// Give up on a part after this long, e.g. `500ms` or `5s`
+        #[arg(long, value_parser = is_duration)]
+        timeout: Option<Duration>,
+    },
+    /// Check solutions against the expected answers in `answers/<year>.toml`
+    Verify {
+        #[arg(long, value_parser = is_year)]
+        year: Option<u16>,
+        #[arg(long, value_parser = is_day)]
+        day: Option<u16>,
+        /// Give up on a part after this long, e.g. `500ms` or `5s`
+        #[arg(long, value_parser = is_duration)]
+        timeout: Option<Duration>,
+    },
+    /// Count the allocations, bytes allocated and peak memory of a day
+    Profile {
+        #[arg(value_parser = is_year)]
+        year: u16,
+        #[arg(value_parser = is_day)]
+        day: u16,
+        /// Only profile this part (profiles both when omitted)
+        #[arg(value_parser = is_part)]
+        part: Option<u8>,
+        /// Read the input from this file instead, e.g. an example input
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+fn run_day(
+    year: u16,
+    day: u16,
+    part: Option<u8>,
+    format: Option<Format>,
+    timeout: Option<Duration>,
+    input: Option<&Path>,
+) -> bool {
+    let run = |entry| match input {
+        Some(path) => runner::run_file(entry, path, timeout),
+        None => runner::run(entry, timeout),
+    };
+    let entries: Vec<_> = registry::for_day(year, day)
+        .filter(|e| part.is_none_or(|p| e.part == p))
+        .collect();
+    if entries.is_empty() {
+        eprintln!("No solution found for {} Day {:02}", year, day);
+        return false;
+    }
+    if let Some(format) = format {
+        let outcomes: Vec<_> = entries.into_iter().map(run).collect();
+        return emit(&outcomes, format);
+    }
+
+    let mut ok = true;
+    for entry in entries {
+        let outcome = run(entry);
+        let elapsed = outcome.elapsed;
+        match outcome.status {
+            Status::Solved(answer) if answer.is_multiline() => {
+                println!("{} ({:.2?}):\n{}", entry, elapsed, answer)
+            }
+            Status::Solved(answer) => println!("{}: {} ({:.2?})", entry, answer, elapsed),
+            Status::Failed(e) => {
+                ok = false;
+                eprintln!("{} failed: {}", entry, e);
+            }
+            Status::Panicked(message) => {
+                ok = false;
+                eprintln!("{} panicked: {}", entry, message);
+            }
+            Status::TimedOut => {
+                ok = false;
+                eprintln!("{}: TIMEOUT ({:.2?})", entry, elapsed);
+            }
+            Status::NoInput => {
+                let path = input.map_or(entry.input_path(), |p| p.display().to_string());
+                eprintln!("Input file \"{}\" not found", path);
+                return false;
+            }
+        }
+    }
+    ok
+}
+
+/// Runs every day of `year`, or of every year, and prints a sorted summary.
+/// Days without an input file are skipped.
+fn run_batch(year: Option<u16>, format: Option<Format>, timeout: Option<Duration>) -> bool {
+    let entries: Vec<_> = registry::all()
+        .filter(|e| year.is_none_or(|y| e.year == y))
+        .collect();
+
+    quiet_panics();
+    let start = Instant::now();
+    let outcomes = runner::run_parallel(&entries, timeout);
+    let wall = start.elapsed();
+
+    if let Some(format) = format {
+        let ran: Vec<_> = outcomes
+            .into_iter()
+            .filter(|o| !matches!(o.status, Status::NoInput))
+            .collect();
+        return emit(&ran, format);
+    }
+
+    let (mut solved, mut failed, mut skipped) = (0, 0, 0);
+    let mut total = Duration::ZERO;
+    println!(
+        "{:<4}  {:>3}  {:>4}  {:>10}  Answer",
+        "Year", "Day", "Part", "Time"
+    );
+    for outcome in &outcomes {
+        let answer = match &outcome.status {
+            Status::NoInput => {
+                skipped += 1;
+                continue;
+            }
+            Status::Solved(answer) => {
+                solved += 1;
+                summary(answer)
+            }
+            Status::Failed(e) => {
+                failed += 1;
+                format!("error: {}", e)
+            }
+            Status::Panicked(message) => {
+                failed += 1;
+                format!("panicked: {}", message)
+            }
+            Status::TimedOut => {
+                failed += 1;
+                "TIMEOUT".to_string()
+            }
+        };
+        total += outcome.elapsed;
+        let entry = outcome.entry;
+        println!(
+            "{:<4}  {:>3}  {:>4}  {:>10}  {}",
+            entry.year,
+            entry.day,
+            entry.part,
+            format!("{:.2?}", outcome.elapsed),
+            answer
+        );
+    }
+    println!(
+        "\n{} solved, {} failed, {} skipped (no input) in {:.2?} ({:.2?} total solve time)",
+        solved, failed, skipped, wall, total
+    );
+    failed == 0
+}
+
+/// Runs each part of a day once on the main thread, reporting its memory use.
+/// Parts run one after the other so their counts don't mix.
+fn profile(year: u16, day: u16, part: Option<u8>, input: Option<&Path>) -> bool {
+    let entries: Vec<_> = registry::for_day(year, day)
+        .filter(|e| part.is_none_or(|p| e.part == p))
+        .collect();
+    let Some(first) = entries.first() else {
+        eprintln!("No solution found for {} Day {:02}", year, day);
+        return false;
+    };
+    let path = input.map_or(PathBuf::from(first.input_path()), Path::to_path_buf);
+    let Ok(content) = fs::read_to_string(&path) else {
+        eprintln!("Input file \"{}\" not found", path.display());
+        return false;
+    };
+
+    profile::enable();
+    let mut ok = true;
+    println!(
+        "{:>4}  {:>11}  {:>12}  {:>12}  {:>10}",
+        "Part", "Allocations", "Allocated", "Peak", "Time"
+    );
+    for entry in entries {
+        let (outcome, usage) = profile::run_input(entry, &content);
+        let note = match &outcome.status {
+            Status::Solved(_) => String::new(),
+            Status::Failed(e) => format!("  error: {}", e),
+            Status::Panicked(message) => format!("  panicked: {}", message),
+            Status::TimedOut | Status::NoInput => unreachable!("profiling runs without timeout"),
+        };
+        ok &= note.is_empty();
+        println!(
+            "{:>4}  {:>11}  {:>12}  {:>12}  {:>10}{}",
+            entry.part,
+            usage.allocations,
+            format_bytes(usage.allocated_bytes),
+            format_bytes(usage.peak_bytes),
+            format!("{:.2?}", outcome.elapsed),
+            note
+        );
+    }
+    ok
+}
+
+/// Polls the day's source and inputs, rebuilding and re-running on changes.
+fn watch(year: u16, day: u16, interval: Duration, timeout: Option<Duration>) -> bool {
+    println!(
+        "Watching {} and inputs/{}/day{:02}*.txt",
+        watch::source_path(year, day).display(),
+        year,
+        day
+    );
+    let mut last = BTreeMap::new();
+    loop {
+        let current = watch::snapshot(year, day);
+        if current != last {
+            last = current;
+            rebuild_and_run(year, day, timeout);
+        }
+        thread::sleep(interval);
+    }
+}
+
+/// Rebuilds the binary, then runs it on every input of the day and prints
+/// the answers next to the ones the day's tests (or `answers/`) expect.
+fn rebuild_and_run(year: u16, day: u16, timeout: Option<Duration>) {
+    println!(
+        "\n[{}] Rebuilding...",
+        chrono::Local::now().format("%H:%M:%S")
+    );
+    match process::Command::new("cargo")
+        .args(["build", "--release", "--bin", "aoc"])
+        .status()
+    {
+        Ok(status) if status.success() => {}
+        Ok(_) => {
+            eprintln!("Build failed, waiting for changes");
+            return;
+        }
+        Err(e) => {
+            eprintln!("Failed to run cargo: {}", e);
+            return;
+        }
+    }
+
+    let expectations = fs::read_to_string(watch::source_path(year, day))
+        .map(|source| watch::expectations(&source))
+        .unwrap_or_default();
+    let answers = Answers::load(year).unwrap_or_default();
+    let real_input = format!("day{:02}.txt", day);
+
+    for path in watch::input_paths(year, day) {
+        let name = watch::file_name(&path);
+        // Through cargo, which knows where the binary it just built is
+        let mut command = process::Command::new("cargo");
+        command
+            .args(["run", "--release", "--quiet", "--bin", "aoc", "--"])
+            .args(["run", &year.to_string(), &day.to_string()])
+            .arg("--input")
+            .arg(&path)
+            .args(["--format", "json"]);
+        if let Some(timeout) = timeout {
+            command.arg(format!("--timeout={}ms", timeout.as_millis()));
+        }
+        let output = match command.output() {
+            Ok(output) => output,
+            Err(e) => {
+                eprintln!("Failed to run cargo: {}", e);
+                return;
+            }
+        };
+        let Ok(records) = serde_json::from_slice::<Vec<Record>>(&output.stdout) else {
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            continue;
+        };
+
+        for record in records {
+            let expected = expectations
+                .iter()
+                .find(|e| e.input == name && e.part == record.part)
+                .map(|e| &e.answer)
+                .or_else(|| {
+                    (name == real_input)
+                        .then(|| answers.get(day, record.part))
+                        .flatten()
+                });
+            let got = match (&record.answer, &record.error) {
+                (Some(answer), _) => summary(answer),
+                (None, Some(e)) => format!("{}: {}", record.status.as_str(), e),
+                (None, None) => record.status.as_str().to_string(),
+            };
+            let check = match (expected, &record.answer) {
+                (None, _) => String::new(),
+                (Some(expected), Some(answer)) if expected == answer => "ok".to_string(),
+                (Some(expected), _) => format!("MISMATCH, expected {}", summary(expected)),
+            };
+            let time = record
+                .time_ns
+                .map(|ns| format!("{:.2?}", Duration::from_nanos(ns as u64)))
+                .unwrap_or_default();
+            println!(
+                "{:<14} Part {}: {:<20} {:>10}  {}",
+                name, record.part, got, time, check
+            );
+        }
+    }
+}
+
+/// Writes `outcomes` to stdout as `format` records. Returns whether every
+/// part was solved.
+fn emit(outcomes: &[Outcome], format: Format) -> bool {
+    let records: Vec<Record> = outcomes.iter().map(Record::from).collect();
+    if let Err(e) = report::write(&mut io::stdout().lock(), &records, format) {
+        eprintln!("Failed to write output: {}", e);
+        return false;
+    }
+    records.iter().all(|r| r.status == RecordStatus::Solved)
+}
+
+/// The runner reports panics per part, so the default hook's message and
+/// backtrace would only interleave with the summary.
+fn quiet_panics() {
+    panic::set_hook(Box::new(|_| {}));
+}
+
+fn verify(year: Option<u16>, day: Option<u16>, timeout: Option<Duration>) -> bool {
+    quiet_panics();
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    println!(
+        "{:<4}  {:>3}  {:>4}  {:<7}  {:>10}  Details",
+        "Year", "Day", "Part", "Status", "Time"
+    );
+    for y in registry::years().filter(|y| year.is_none_or(|year| *y == year)) {
+        let answers = match Answers::load(y) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{:#}", e);
+                return false;
+            }
+        };
+        let entries: Vec<_> = registry::for_year(y)
+            .filter(|e| day.is_none_or(|d| e.day == d))
+            .collect();
+        for outcome in runner::run_parallel(&entries, timeout) {
+            let entry = outcome.entry;
+            let elapsed = outcome.elapsed;
+            let expected = answers.get(entry.day, entry.part);
+            let (status, time, details) = match (outcome.status, expected) {
+                (Status::NoInput, _) => {
+                    missing += 1;
+                    (
+                        "missing",
+                        None,
+                        format!("no input at {}", entry.input_path()),
+                    )
+                }
+                (Status::Failed(e), _) => {
+                    failed += 1;
+                    ("error", Some(elapsed), e)
+                }
+                (Status::Panicked(message), _) => {
+                    failed += 1;
+                    ("error", Some(elapsed), format!("panicked: {}", message))
+                }
+                (Status::TimedOut, _) => {
+                    failed += 1;
+                    ("TIMEOUT", Some(elapsed), String::new())
+                }
+                (Status::Solved(answer), None) => {
+                    missing += 1;
+                    (
+                        "missing",
+                        Some(elapsed),
+                        format!("no expected answer, got {}", summary(&answer)),
+                    )
+                }
+                (Status::Solved(answer), Some(expected)) if answer == *expected => {
+                    passed += 1;
+                    ("pass", Some(elapsed), String::new())
+                }
+                (Status::Solved(answer), Some(expected)) => {
+                    failed += 1;
+                    let details =
+                        format!("expected {}, got {}", summary(expected), summary(&answer));
+                    ("FAIL", Some(elapsed), details)
+                }
+            };
+            let time = time.map(|t| format!("{:.2?}", t)).unwrap_or_default();
+            println!(
+                "{:<4}  {:>3}  {:>4}  {:<7}  {:>10}  {}",
+                entry.year, entry.day, entry.part, status, time, details
+            );
+        }
+    }
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        passed, failed, missing
+    );
+    failed == 0
+}
+
+/// Single-line form of an answer for the summary tables.
+fn summary(answer: &Answer) -> String {
+    if answer.is_multiline() {
+        format!("{:?}", answer.to_string())
+    } else {
+        answer.to_string()
+    }
+}
+
 fn main() {
-    println!("Hello, world!");
+    let cli = Cli::parse();
+
+    let ok = match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            whole_year,
+            all,
+            format,
+            timeout,
+            input,
+        } => match (year.or(whole_year), day) {
+            (Some(year), Some(day)) => run_day(year, day, part, format, timeout, input.as_deref()),
+            (year, _) => run_batch(year.filter(|_| !all), format, timeout),
+        },
+        Command::Watch {
+            year,
+            day,
+            interval,
+            timeout,
+        } => watch(year, day, interval, timeout),
+        Command::Verify { year, day, timeout } => verify(year, day, timeout),
+        Command::Profile {
+            year,
+            day,
+            part,
+            input,
+        } => profile(year, day, part, input.as_deref()),
+    };
+
+    if !ok {
+        process::exit(1);
+    }
 }
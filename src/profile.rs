@@ -0,0 +1,161 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicU64, Ordering};
+
+use crate::registry::Entry;
+use crate::runner::{self, Outcome};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+// Signed, as memory allocated before counting was enabled may be freed after
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+/// The system allocator, counting allocations and live bytes as it goes.
+/// Binaries opt in with
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: AOC::profile::CountingAllocator = AOC::profile::CountingAllocator;
+/// ```
+///
+/// It only counts once [`enable`] was called, until then it is the system
+/// allocator plus one relaxed load, so commands that time solutions aren't
+/// slowed down by it. Without it installed, [`measure`] reports zero usage.
+pub struct CountingAllocator;
+
+/// Starts counting allocations made through [`CountingAllocator`], for the
+/// rest of the process.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+fn allocated(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+fn freed(size: usize) {
+    LIVE.fetch_sub(size as isize, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if enabled() && !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if enabled() && !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if enabled() {
+            freed(layout.size());
+        }
+    }
+
+    /// Counted as a new allocation of `new_size` replacing the old one,
+    /// which is what growing a `Vec` or `String` costs.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if enabled() && !new_ptr.is_null() {
+            allocated(new_size);
+            freed(layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Memory used while running something.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// Number of allocations, reallocations included.
+    pub allocations: u64,
+    /// Bytes requested over all those allocations.
+    pub allocated_bytes: u64,
+    /// Highest number of bytes live at once, above what was live before.
+    pub peak_bytes: u64,
+}
+
+/// Runs `f` and reports the memory it used, which is only counted after
+/// [`enable`]. The counters are process wide,
+/// so anything allocating on other threads at the same time is included;
+/// don't measure two things concurrently.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Usage) {
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+
+    let result = f();
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated_bytes: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak_bytes: (PEAK.load(Ordering::Relaxed) - live).max(0) as u64,
+    };
+    (result, usage)
+}
+
+/// Runs `entry` against `input` like [`runner::run_input`], measuring its
+/// memory use.
+pub fn run_input(entry: &'static Entry, input: &str) -> (Outcome, Usage) {
+    measure(|| runner::run_input(entry, input))
+}
+
+/// Human readable byte count, e.g. `1.50 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        // The lib's tests run on the system allocator, so this feeds the
+        // counters what `CountingAllocator` would
+        let (_, usage) = measure(|| {
+            allocated(1 << 20);
+            freed(1 << 20);
+            allocated(1 << 10);
+        });
+        // Other tests run concurrently, so only lower bounds hold
+        assert!(usage.allocations >= 2);
+        assert!(usage.allocated_bytes >= (1 << 20) + (1 << 10));
+        assert!(usage.peak_bytes >= 1 << 20);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 << 30), "3.00 GiB");
+    }
+}
@@ -0,0 +1,264 @@
+use anyhow::Context;
+use std::any::Any;
+use std::fmt::{self, Display};
+
+use crate::answer::Answer;
+use crate::solution::Solution;
+use crate::solutions::{PHASED, REGISTRY};
+
+/// A single registered puzzle part.
+#[derive(Debug)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u16,
+    pub part: u8,
+    /// Path of the wrapped `solve_*` function, e.g. `day05::solve_2023_05_01`.
+    pub function: &'static str,
+    /// Uniform adapter over the solution, taking the puzzle input itself.
+    pub run: fn(&str) -> anyhow::Result<Answer>,
+}
+
+impl Entry {
+    pub fn input_path(&self) -> String {
+        format!("inputs/{}/day{:02}.txt", self.year, self.day)
+    }
+
+    /// Display name, also used as the criterion benchmark id.
+    pub fn name(&self) -> String {
+        format!("{} Day {:02} Part {}", self.year, self.day, self.part)
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Builds an [`Entry`] for a `solve_*` function. Extra arguments are
+/// passed after the input, for days whose real input needs parameters.
+macro_rules! solution {
+    ($year:literal, $day:literal, $part:literal, $func:path $(, $arg:expr)*) => {
+        $crate::registry::Entry {
+            year: $year,
+            day: $day,
+            part: $part,
+            function: stringify!($func),
+            run: |input| $crate::answer::IntoAnswer::into_answer($func(input $(, $arg)*)),
+        }
+    };
+}
+pub(crate) use solution;
+
+/// A day implementing [`Solution`], with its phases type-erased so they can
+/// be timed separately: the benches parse once, then run each part on the
+/// parsed input.
+pub struct Phased {
+    pub year: u16,
+    pub day: u16,
+    pub parse: fn(&str) -> anyhow::Result<Box<dyn Any>>,
+    pub part1: fn(&dyn Any) -> anyhow::Result<Answer>,
+    pub part2: fn(&dyn Any) -> anyhow::Result<Answer>,
+}
+
+impl Phased {
+    /// Criterion benchmark id of the parse phase, next to the parts'
+    /// [`Entry::name`].
+    pub fn parse_name(&self) -> String {
+        format!("{} Day {:02} Parse", self.year, self.day)
+    }
+
+    pub fn part(&self, part: u8) -> fn(&dyn Any) -> anyhow::Result<Answer> {
+        match part {
+            1 => self.part1,
+            _ => self.part2,
+        }
+    }
+}
+
+/// Builds a [`Phased`] for the `Solution` implementation `S`.
+pub const fn phased<S: Solution>(year: u16, day: u16) -> Phased
+where
+    S::Parsed: 'static,
+{
+    Phased {
+        year,
+        day,
+        parse: parse_any::<S>,
+        part1: part1_any::<S>,
+        part2: part2_any::<S>,
+    }
+}
+
+fn parse_any<S: Solution>(input: &str) -> anyhow::Result<Box<dyn Any>>
+where
+    S::Parsed: 'static,
+{
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> anyhow::Result<&S::Parsed>
+where
+    S::Parsed: 'static,
+{
+    parsed.downcast_ref().context("Parsed input of another day")
+}
+
+fn part1_any<S: Solution>(parsed: &dyn Any) -> anyhow::Result<Answer>
+where
+    S::Parsed: 'static,
+{
+    Ok(S::part1(downcast::<S>(parsed)?)?.into())
+}
+
+fn part2_any<S: Solution>(parsed: &dyn Any) -> anyhow::Result<Answer>
+where
+    S::Parsed: 'static,
+{
+    Ok(S::part2(downcast::<S>(parsed)?)?.into())
+}
+
+/// The phases of a day, if it implements [`Solution`] and is registered in
+/// its year's `PHASED` table.
+pub fn phases(year: u16, day: u16) -> Option<&'static Phased> {
+    PHASED
+        .iter()
+        .flat_map(|year| year.iter())
+        .find(|p| p.year == year && p.day == day)
+}
+
+/// Every registered solution, ordered by year, day and part.
+pub fn all() -> impl Iterator<Item = &'static Entry> {
+    REGISTRY.iter().flat_map(|year| year.iter())
+}
+
+pub fn years() -> impl Iterator<Item = u16> {
+    REGISTRY
+        .iter()
+        .filter_map(|entries| entries.first().map(|e| e.year))
+}
+
+pub fn for_year(year: u16) -> impl Iterator<Item = &'static Entry> {
+    all().filter(move |e| e.year == year)
+}
+
+pub fn for_day(year: u16, day: u16) -> impl Iterator<Item = &'static Entry> {
+    for_year(year).filter(move |e| e.day == day)
+}
+
+pub fn find(year: u16, day: u16, part: u8) -> Option<&'static Entry> {
+    for_day(year, day).find(|e| e.part == part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn test_registry_is_sorted_and_unique() {
+        let keys: Vec<(u16, u16, u8)> = all().map(|e| (e.year, e.day, e.part)).collect();
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_registry_names_match_functions() {
+        for entry in all() {
+            let day = format!("solve_{}_{:02}", entry.year, entry.day);
+            let part = format!("{}_{:02}", day, entry.part);
+            // A day may share one function between its parts, told apart by
+            // the arguments it's registered with
+            assert!(
+                entry.function.ends_with(&part) || entry.function.ends_with(&day),
+                "{}",
+                entry.function
+            );
+        }
+    }
+
+    /// The `pub mod dayNN;` lines of each `src/solutions/yearXXXX/mod.rs`,
+    /// as `(year, day)`. Every year directory must have a registry table.
+    fn declared_days() -> Vec<(u16, u16)> {
+        let solutions = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/solutions");
+        let mut years: Vec<u16> = fs::read_dir(&solutions)
+            .unwrap()
+            .filter_map(|entry| {
+                entry
+                    .ok()?
+                    .file_name()
+                    .to_str()?
+                    .strip_prefix("year")?
+                    .parse()
+                    .ok()
+            })
+            .collect();
+        years.sort();
+        assert_eq!(years, super::years().collect::<Vec<_>>());
+
+        let mut declared = Vec::new();
+        for year in years {
+            let path = solutions.join(format!("year{}/mod.rs", year));
+            let source = fs::read_to_string(path).unwrap();
+            declared.extend(source.lines().filter_map(|line| {
+                let day = line.strip_prefix("pub mod day")?.strip_suffix(';')?;
+                Some((year, day.parse().unwrap()))
+            }));
+        }
+        declared
+    }
+
+    #[test]
+    fn test_every_declared_day_is_registered() {
+        let declared = declared_days();
+        for &(year, day) in &declared {
+            assert!(find(year, day, 1).is_some(), "{} day {} part 1", year, day);
+            // Day 25 has no second puzzle
+            if day != 25 {
+                assert!(find(year, day, 2).is_some(), "{} day {} part 2", year, day);
+            }
+        }
+        for entry in all() {
+            assert!(
+                declared.contains(&(entry.year, entry.day)),
+                "{} has no module",
+                entry
+            );
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        let entry = find(2023, 5, 2).unwrap();
+        assert_eq!(entry.name(), "2023 Day 05 Part 2");
+        assert_eq!(entry.input_path(), "inputs/2023/day05.txt");
+        assert!(find(2023, 25, 2).is_none());
+    }
+
+    #[test]
+    fn test_run_from_str() {
+        let entry = find(2023, 9, 1).unwrap();
+        let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+        assert_eq!((entry.run)(input).unwrap(), Answer::Int(114));
+    }
+
+    #[test]
+    fn test_phases_match_entries() {
+        let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+        let phased = phases(2023, 9).unwrap();
+        let parsed = (phased.parse)(input).unwrap();
+        for part in 1..=2 {
+            let entry = find(2023, 9, part).unwrap();
+            assert_eq!(
+                (phased.part(part))(parsed.as_ref()).unwrap(),
+                (entry.run)(input).unwrap()
+            );
+        }
+        assert_eq!(phased.parse_name(), "2023 Day 09 Parse");
+
+        // Every phased day is also registered per part
+        for phased in PHASED.iter().flat_map(|year| year.iter()) {
+            assert!(find(phased.year, phased.day, 2).is_some());
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Write};
+
+use crate::answer::Answer;
+use crate::runner::{Outcome, Status};
+
+/// Machine-readable output formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Json,
+    Csv,
+    Tsv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordStatus {
+    Solved,
+    Failed,
+    Panicked,
+    Timeout,
+    NoInput,
+}
+
+impl RecordStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordStatus::Solved => "solved",
+            RecordStatus::Failed => "failed",
+            RecordStatus::Panicked => "panicked",
+            RecordStatus::Timeout => "timeout",
+            RecordStatus::NoInput => "no_input",
+        }
+    }
+}
+
+/// [`Record::part`] of the time spent parsing the input, for days that
+/// benchmark it separately from the parts.
+pub const PARSE: u8 = 0;
+
+/// One row of machine-readable output. `aoc run` fills in answers and wall
+/// times, `update_benchmarks` fills in criterion medians without answers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u16,
+    /// 1 or 2, or [`PARSE`] for a separately benchmarked parse.
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub status: RecordStatus,
+    /// Solve time in nanoseconds.
+    pub time_ns: Option<f64>,
+    pub error: Option<String>,
+}
+
+impl From<&Outcome> for Record {
+    fn from(outcome: &Outcome) -> Self {
+        let entry = outcome.entry;
+        let (answer, status, error) = match &outcome.status {
+            Status::Solved(answer) => (Some(answer.clone()), RecordStatus::Solved, None),
+            Status::Failed(e) => (None, RecordStatus::Failed, Some(e.clone())),
+            Status::Panicked(message) => (None, RecordStatus::Panicked, Some(message.clone())),
+            Status::TimedOut => (None, RecordStatus::Timeout, None),
+            Status::NoInput => (None, RecordStatus::NoInput, None),
+        };
+        let time_ns = match status {
+            RecordStatus::NoInput => None,
+            _ => Some(outcome.elapsed.as_nanos() as f64),
+        };
+        Record {
+            year: entry.year,
+            day: entry.day,
+            part: entry.part,
+            answer,
+            status,
+            time_ns,
+            error,
+        }
+    }
+}
+
+const HEADER: [&str; 7] = [
+    "year", "day", "part", "answer", "status", "time_ns", "error",
+];
+
+impl Record {
+    fn fields(&self) -> [String; 7] {
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            self.answer
+                .as_ref()
+                .map(Answer::to_string)
+                .unwrap_or_default(),
+            self.status.as_str().to_string(),
+            self.time_ns.map(|ns| ns.to_string()).unwrap_or_default(),
+            self.error.clone().unwrap_or_default(),
+        ]
+    }
+}
+
+/// Quotes a CSV field when it contains a separator, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// TSV has no quoting, so tabs and line breaks are escaped instead.
+fn tsv_field(field: &str) -> String {
+    field
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+pub fn write<W: Write>(out: &mut W, records: &[Record], format: Format) -> io::Result<()> {
+    let (separator, escape): (&str, fn(&str) -> String) = match format {
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, records)?;
+            return writeln!(out);
+        }
+        Format::Csv => (",", csv_field),
+        Format::Tsv => ("\t", tsv_field),
+    };
+    writeln!(out, "{}", HEADER.join(separator))?;
+    for record in records {
+        let fields: Vec<String> = record.fields().iter().map(|f| escape(f)).collect();
+        writeln!(out, "{}", fields.join(separator))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                year: 2022,
+                day: 10,
+                part: 2,
+                answer: Some(Answer::Art("#..#\n.##.".to_string())),
+                status: RecordStatus::Solved,
+                time_ns: Some(1500.0),
+                error: None,
+            },
+            Record {
+                year: 2023,
+                day: 5,
+                part: 1,
+                answer: None,
+                status: RecordStatus::Failed,
+                time_ns: Some(20.0),
+                error: Some("bad seed, \"x\"".to_string()),
+            },
+        ]
+    }
+
+    fn render(format: Format) -> String {
+        let mut out = Vec::new();
+        write(&mut out, &records(), format).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            render(Format::Csv),
+            "year,day,part,answer,status,time_ns,error\n\
+             2022,10,2,\"#..#\n.##.\",solved,1500,\n\
+             2023,5,1,,failed,20,\"bad seed, \"\"x\"\"\"\n"
+        );
+    }
+
+    #[test]
+    fn test_tsv() {
+        assert_eq!(
+            render(Format::Tsv),
+            "year\tday\tpart\tanswer\tstatus\ttime_ns\terror\n\
+             2022\t10\t2\t#..#\\n.##.\tsolved\t1500\t\n\
+             2023\t5\t1\t\tfailed\t20\tbad seed, \"x\"\n"
+        );
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let back: Vec<Record> = serde_json::from_str(&render(Format::Json)).unwrap();
+        assert_eq!(back, records());
+    }
+}
@@ -0,0 +1,329 @@
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use std::any::Any;
+use std::fs;
+use std::panic;
+use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::cancel::{self, CancellationToken};
+use crate::registry::Entry;
+
+/// Some solutions recurse deeply, so timed workers and the batch pool get
+/// more room than the 2 MiB default for spawned threads.
+const WORKER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// How long a timed out worker gets to notice it was cancelled before the
+/// runner moves on.
+const CANCEL_GRACE: Duration = Duration::from_secs(1);
+
+/// How running a single puzzle part ended.
+#[derive(Debug)]
+pub enum Status {
+    Solved(Answer),
+    Failed(String),
+    Panicked(String),
+    /// The solution was still running when its timeout ran out.
+    TimedOut,
+    /// There is no input file, so the solution was not run.
+    NoInput,
+}
+
+#[derive(Debug)]
+pub struct Outcome {
+    pub entry: &'static Entry,
+    pub status: Status,
+    /// Time spent inside the solution, excluding reading the input.
+    pub elapsed: Duration,
+}
+
+/// Runs `entry` against its real input, giving up after `timeout` if set.
+pub fn run(entry: &'static Entry, timeout: Option<Duration>) -> Outcome {
+    run_file(entry, entry.input_path(), timeout)
+}
+
+/// Like [`run`], but against the input at `path`, e.g. an example input.
+pub fn run_file(
+    entry: &'static Entry,
+    path: impl AsRef<Path>,
+    timeout: Option<Duration>,
+) -> Outcome {
+    let Ok(input) = fs::read_to_string(path) else {
+        return Outcome {
+            entry,
+            status: Status::NoInput,
+            elapsed: Duration::ZERO,
+        };
+    };
+    match timeout {
+        Some(timeout) => run_with_timeout(entry, input, timeout),
+        None => run_input(entry, &input),
+    }
+}
+
+/// Runs `entry` on a worker thread and reports [`Status::TimedOut`] if it
+/// takes longer than `timeout`.
+///
+/// The worker's [`CancellationToken`] is cancelled at that point, and the
+/// runner waits up to [`CANCEL_GRACE`] for it to stop, so a solution that
+/// checks the token doesn't compete with the next job. Threads can't be
+/// killed, so a solution that never checks it keeps running in the
+/// background until it finishes or the process exits.
+pub fn run_with_timeout(entry: &'static Entry, input: String, timeout: Duration) -> Outcome {
+    let token = CancellationToken::new();
+    let worker_token = token.clone();
+    let (tx, rx) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .name(entry.name())
+        .stack_size(WORKER_STACK_SIZE)
+        .spawn(move || {
+            let outcome = cancel::with_token(worker_token, || run_input(entry, &input));
+            // The receiver is gone if we already timed out
+            let _ = tx.send(outcome);
+        });
+    if let Err(e) = spawned {
+        return Outcome {
+            entry,
+            status: Status::Failed(format!("Failed to spawn worker: {}", e)),
+            elapsed: Duration::ZERO,
+        };
+    }
+
+    let status = match rx.recv_timeout(timeout) {
+        Ok(outcome) => return outcome,
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            // Whatever it returns once it notices is dropped
+            let _ = rx.recv_timeout(CANCEL_GRACE);
+            Status::TimedOut
+        }
+        Err(RecvTimeoutError::Disconnected) => {
+            Status::Panicked("worker exited without a result".to_string())
+        }
+    };
+    Outcome {
+        entry,
+        status,
+        elapsed: timeout,
+    }
+}
+
+/// Runs `entry` against `input`, turning a panic into [`Status::Panicked`]
+/// so one broken day can't take down a whole batch.
+pub fn run_input(entry: &'static Entry, input: &str) -> Outcome {
+    let start = Instant::now();
+    let result = panic::catch_unwind(|| (entry.run)(input));
+    let elapsed = start.elapsed();
+    let status = match result {
+        Ok(Ok(answer)) => Status::Solved(answer),
+        Ok(Err(e)) => Status::Failed(e.to_string()),
+        Err(payload) => Status::Panicked(panic_message(payload.as_ref())),
+    };
+    Outcome {
+        entry,
+        status,
+        elapsed,
+    }
+}
+
+/// Runs `entries` on a thread pool of their own, with the same stack size
+/// as the timed workers. Outcomes come back in the same order as `entries`.
+///
+/// With a timeout the pool's threads mostly block waiting for their workers,
+/// so it can't be rayon's global pool: solutions using `par_iter` would
+/// queue their work behind those waits and time out.
+///
+/// A timed out solution that doesn't poll [`cancel::is_cancelled`] keeps its
+/// worker thread busy while the rest of the batch runs, see
+/// [`run_with_timeout`].
+pub fn run_parallel(entries: &[&'static Entry], timeout: Option<Duration>) -> Vec<Outcome> {
+    let pool = match ThreadPoolBuilder::new()
+        .stack_size(WORKER_STACK_SIZE)
+        .build()
+    {
+        Ok(pool) => pool,
+        Err(e) => {
+            return entries
+                .iter()
+                .map(|&entry| Outcome {
+                    entry,
+                    status: Status::Failed(format!("Failed to build thread pool: {}", e)),
+                    elapsed: Duration::ZERO,
+                })
+                .collect()
+        }
+    };
+    pool.install(|| {
+        entries
+            .par_iter()
+            .map(|entry| run(entry, timeout))
+            .collect()
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+
+    static SOLVES: Entry = Entry {
+        year: 2023,
+        day: 1,
+        part: 1,
+        function: "solves",
+        run: |input| Ok(Answer::from(input.len())),
+    };
+
+    static PANICS: Entry = Entry {
+        year: 2023,
+        day: 1,
+        part: 2,
+        function: "panics",
+        run: |_| panic!("index out of bounds"),
+    };
+
+    static SPINS: Entry = Entry {
+        year: 2023,
+        day: 2,
+        part: 1,
+        function: "spins",
+        run: |_| {
+            while !cancel::is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            Err(cancel::Cancelled.into())
+        },
+    };
+
+    /// Year with no real inputs, so the test can create its own.
+    static PARALLEL: Entry = Entry {
+        year: 9999,
+        day: 1,
+        part: 1,
+        function: "parallel",
+        run: |_| Ok(Answer::from((0..1000usize).into_par_iter().sum::<usize>())),
+    };
+
+    /// Recurses deep enough to overflow a default 2 MiB stack.
+    static RECURSES: Entry = Entry {
+        year: 9999,
+        day: 2,
+        part: 1,
+        function: "recurses",
+        run: |_| Ok(Answer::from(depth(1000))),
+    };
+
+    fn depth(n: usize) -> usize {
+        let frame = std::hint::black_box([1u8; 4096]);
+        match n {
+            0 => 0,
+            _ => depth(n - 1) + frame[n % frame.len()] as usize,
+        }
+    }
+
+    /// Guards the inputs the tests below create.
+    static INPUTS: Mutex<()> = Mutex::new(());
+
+    /// [`run_parallel`] with an empty input file for each of `entries`.
+    fn run_parallel_on_empty_inputs(
+        entries: &[&'static Entry],
+        timeout: Option<Duration>,
+    ) -> Vec<Outcome> {
+        let _guard = INPUTS.lock().unwrap();
+        for entry in entries {
+            let input = entry.input_path();
+            fs::create_dir_all(Path::new(&input).parent().unwrap()).unwrap();
+            fs::write(&input, "").unwrap();
+        }
+        let outcomes = run_parallel(entries, timeout);
+        fs::remove_dir_all("inputs/9999").unwrap();
+        // Only goes if there are no real inputs either
+        let _ = fs::remove_dir("inputs");
+        outcomes
+    }
+
+    static STOPPED: AtomicBool = AtomicBool::new(false);
+
+    static STOPS: Entry = Entry {
+        year: 2023,
+        day: 2,
+        part: 2,
+        function: "stops",
+        run: |_| {
+            while !cancel::is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            STOPPED.store(true, Ordering::Relaxed);
+            Err(cancel::Cancelled.into())
+        },
+    };
+
+    #[test]
+    fn test_run_input() {
+        let outcome = run_input(&SOLVES, "abc");
+        assert!(matches!(outcome.status, Status::Solved(Answer::Int(3))));
+    }
+
+    #[test]
+    fn test_panic_is_caught() {
+        let outcome = run_input(&PANICS, "abc");
+        match outcome.status {
+            Status::Panicked(message) => assert_eq!(message, "index out of bounds"),
+            status => panic!("unexpected status {:?}", status),
+        }
+    }
+
+    #[test]
+    fn test_timeout() {
+        let outcome = run_with_timeout(&SPINS, String::new(), Duration::from_millis(20));
+        assert!(matches!(outcome.status, Status::TimedOut));
+
+        let outcome = run_with_timeout(&SOLVES, "abc".to_string(), Duration::from_secs(10));
+        assert!(matches!(outcome.status, Status::Solved(Answer::Int(3))));
+    }
+
+    #[test]
+    fn test_timeout_waits_for_cancellation() {
+        let outcome = run_with_timeout(&STOPS, String::new(), Duration::from_millis(20));
+        assert!(matches!(outcome.status, Status::TimedOut));
+        assert!(STOPPED.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_parallel_solutions_in_timed_batch() {
+        // More entries than the global pool has threads, each waiting on a
+        // solution that needs that pool
+        let entries = vec![&PARALLEL; rayon::current_num_threads() + 1];
+        let outcomes = run_parallel_on_empty_inputs(&entries, Some(Duration::from_secs(10)));
+        for outcome in outcomes {
+            assert!(
+                matches!(outcome.status, Status::Solved(Answer::Int(499500))),
+                "{:?}",
+                outcome.status
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_stack_size() {
+        let outcomes = run_parallel_on_empty_inputs(&[&RECURSES], None);
+        assert!(matches!(
+            outcomes[0].status,
+            Status::Solved(Answer::Int(1000))
+        ));
+    }
+}
@@ -0,0 +1,619 @@
+use regex::Regex;
+use serde::Serialize;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+use std::str::FromStr;
+
+/// A planned change to one file. `before` is `None` for a new file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileEdit {
+    pub path: String,
+    pub before: Option<String>,
+    pub after: String,
+}
+
+impl FileEdit {
+    pub fn is_noop(&self) -> bool {
+        self.before.as_deref() == Some(self.after.as_str())
+    }
+
+    pub fn apply(&self) -> io::Result<()> {
+        if let Some(parent) = Path::new(&self.path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, &self.after)
+    }
+}
+
+fn read_existing(path: &str) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// An extra argument of a day's solve functions, e.g. `moves: usize` for a
+/// day whose part 1 runs for 64 moves on the real input.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Param {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Value for the real input, as a Rust expression.
+    pub value: Option<String>,
+}
+
+impl Param {
+    /// The value to pass for the real input, `Default::default()` if unknown.
+    pub fn argument(&self) -> String {
+        self.value
+            .clone()
+            .unwrap_or_else(|| "Default::default()".to_string())
+    }
+}
+
+impl FromStr for Param {
+    type Err = String;
+
+    /// Parses `name:type` or `name:type=value`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (decl, value) = match s.split_once('=') {
+            Some((decl, value)) => (decl, Some(value.trim().to_string())),
+            None => (s, None),
+        };
+        let Some((name, ty)) = decl.split_once(':') else {
+            return Err(format!("Expected name:type[=value], got \"{}\"", s));
+        };
+        let (name, ty) = (name.trim(), ty.trim());
+        let is_ident = |n: &str| {
+            n.chars()
+                .next()
+                .is_some_and(|c| c.is_alphabetic() || c == '_')
+                && n.chars().all(|c| c.is_alphanumeric() || c == '_')
+        };
+        if !is_ident(name) || ty.is_empty() {
+            return Err(format!("Expected name:type[=value], got \"{}\"", s));
+        }
+        Ok(Param {
+            name: name.to_string(),
+            ty: ty.to_string(),
+            value: value.filter(|v| !v.is_empty()),
+        })
+    }
+}
+
+/// The edits needed to register `day` of `year`: its `pub mod` line and
+/// registry entries in the year module, and the year itself in
+/// `src/solutions/mod.rs` when the year is new. Registered days show up in
+/// `aoc run` and the benches without further changes. Edits that are already
+/// in place are left out, so running this twice plans nothing the second time.
+///
+/// `args` are passed to both parts after the input, see
+/// [`crate::registry::solution`].
+pub fn plan_registration(year: u16, day: u16, args: &[String]) -> io::Result<Vec<FileEdit>> {
+    let year_path = format!("src/solutions/year{}/mod.rs", year);
+    let solutions_path = "src/solutions/mod.rs".to_string();
+
+    let year_before = read_existing(&year_path)?;
+    let year_after = add_day_entries(
+        &add_day_module(year_before.as_deref().unwrap_or(&new_year_module()), day),
+        year,
+        day,
+        args,
+    );
+
+    let solutions_before = fs::read_to_string(&solutions_path)?;
+    let solutions_after = add_year(&solutions_before, year);
+
+    let edits = vec![
+        FileEdit {
+            path: year_path,
+            before: year_before,
+            after: year_after,
+        },
+        FileEdit {
+            path: solutions_path,
+            before: Some(solutions_before),
+            after: solutions_after,
+        },
+    ];
+    Ok(edits.into_iter().filter(|e| !e.is_noop()).collect())
+}
+
+/// A year module without any days yet.
+pub fn new_year_module() -> String {
+    "use crate::registry::{solution, Entry};\n\npub static SOLUTIONS: &[Entry] = &[\n];\n"
+        .to_string()
+}
+
+/// Inserts `line` before the first line in `lines` for which `after` is
+/// true, or else before `fallback`.
+fn insert_line(
+    lines: &mut Vec<String>,
+    line: String,
+    after: impl Fn(&str) -> bool,
+    fallback: usize,
+) {
+    let position = lines.iter().position(|l| after(l)).unwrap_or(fallback);
+    lines.insert(position, line);
+}
+
+fn join(lines: Vec<String>) -> String {
+    let mut content = lines.join("\n");
+    content.push('\n');
+    content
+}
+
+/// Adds `pub mod dayNN;` to a year module, keeping the declarations sorted.
+pub fn add_day_module(source: &str, day: u16) -> String {
+    let line = format!("pub mod day{:02};", day);
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    if lines.iter().any(|l| l.trim() == line) {
+        return source.to_string();
+    }
+
+    let is_day_mod = |l: &str| l.starts_with("pub mod day");
+    match lines.iter().rposition(|l| is_day_mod(l)) {
+        Some(last) => insert_line(
+            &mut lines,
+            line.clone(),
+            |l| is_day_mod(l) && l > line.as_str(),
+            last + 1,
+        ),
+        None => {
+            // First day of the year, goes in its own block before the table
+            let table = lines
+                .iter()
+                .position(|l| l.starts_with("pub static SOLUTIONS"))
+                .unwrap_or(lines.len());
+            lines.insert(table, String::new());
+            lines.insert(table, line);
+        }
+    }
+    join(lines)
+}
+
+/// Adds the registry entries for both parts of `day` to a year module's
+/// `SOLUTIONS` table, keeping it sorted by day and part.
+pub fn add_day_entries(source: &str, year: u16, day: u16, args: &[String]) -> String {
+    let entry_re = Regex::new(r"solution!\(\s*(\d+),\s*(\d+),\s*(\d+)").unwrap();
+    let key = |l: &str| -> Option<(u16, u8)> {
+        let c = entry_re.captures(l)?;
+        Some((c[2].parse().ok()?, c[3].parse().ok()?))
+    };
+
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    for part in 1..=2u8 {
+        if lines.iter().any(|l| key(l) == Some((day, part))) {
+            continue;
+        }
+        let args: String = args.iter().map(|a| format!(", {}", a)).collect();
+        let entry = format!(
+            "    solution!({year}, {day}, {part}, day{day:02}::solve_{year}_{day:02}_{part:02}{args}),"
+        );
+        let Some(start) = lines
+            .iter()
+            .position(|l| l.starts_with("pub static SOLUTIONS"))
+        else {
+            continue;
+        };
+        let end = start
+            + lines[start..]
+                .iter()
+                .position(|l| l.starts_with("];"))
+                .unwrap_or(lines.len() - start);
+        let position = (start..end)
+            .find(|&i| key(&lines[i]).is_some_and(|k| k > (day, part)))
+            .unwrap_or(end);
+        lines.insert(position, entry);
+    }
+    join(lines)
+}
+
+/// Declares `yearYYYY` in `src/solutions/mod.rs` and adds its table to
+/// `REGISTRY`, keeping both sorted.
+pub fn add_year(source: &str, year: u16) -> String {
+    let module = format!("pub mod year{};", year);
+    let table = format!("    year{}::SOLUTIONS,", year);
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+
+    if !lines.iter().any(|l| l.trim() == module) {
+        let is_year_mod = |l: &str| l.starts_with("pub mod year");
+        let last = lines
+            .iter()
+            .rposition(|l| is_year_mod(l))
+            .map_or(0, |i| i + 1);
+        insert_line(
+            &mut lines,
+            module.clone(),
+            |l| is_year_mod(l) && l > module.as_str(),
+            last,
+        );
+    }
+
+    if !lines.contains(&table) {
+        let is_table = |l: &str| l.trim_start().starts_with("year") && l.ends_with("::SOLUTIONS,");
+        if let Some(start) = lines
+            .iter()
+            .position(|l| l.starts_with("pub static REGISTRY"))
+        {
+            let end = start
+                + lines[start..]
+                    .iter()
+                    .position(|l| l.starts_with("];"))
+                    .unwrap_or(lines.len() - start);
+            let position = (start..end)
+                .find(|&i| is_table(&lines[i]) && lines[i] > table)
+                .unwrap_or(end);
+            lines.insert(position, table);
+        }
+    }
+    join(lines)
+}
+
+/// What scaffolding can use from a saved puzzle page.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Puzzle {
+    /// Title from the `--- Day N: Title ---` heading.
+    pub title: Option<String>,
+    /// Contents of the `<pre><code>` blocks, in page order without repeats.
+    pub examples: Vec<String>,
+    /// One per part shown on the page.
+    pub parts: Vec<PuzzlePart>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PuzzlePart {
+    /// Index into [`Puzzle::examples`] of the example this part's answer is
+    /// for, the last one shown before it.
+    pub example: Option<usize>,
+    /// The part's last `<code><em>` value, which is the answer for its
+    /// example on every puzzle page so far.
+    pub answer: Option<String>,
+}
+
+/// Pulls the title, examples and example answers out of a puzzle page saved
+/// from the browser. Each part is an `<article>`; a page without them is
+/// treated as a single part.
+pub fn parse_puzzle(html: &str) -> Puzzle {
+    let article_re = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    let title_re = Regex::new(r"<h2[^>]*>--- Day \d+: (.*?) ---</h2>").unwrap();
+    let example_re = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let answer_re = Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+
+    let mut articles: Vec<&str> = article_re
+        .captures_iter(html)
+        .map(|c| c.get(1).unwrap().as_str())
+        .collect();
+    if articles.is_empty() {
+        articles.push(html);
+    }
+
+    let mut puzzle = Puzzle {
+        title: title_re.captures(html).map(|c| unescape(&c[1])),
+        ..Default::default()
+    };
+    for article in articles {
+        let known = puzzle.examples.len();
+        // (offset in the article, index into examples)
+        let mut blocks = Vec::new();
+        for c in example_re.captures_iter(article) {
+            let text = unescape(&c[1]);
+            let index = match puzzle.examples.iter().position(|e| *e == text) {
+                Some(index) => index,
+                None => {
+                    puzzle.examples.push(text);
+                    puzzle.examples.len() - 1
+                }
+            };
+            blocks.push((c.get(0).unwrap().start(), index));
+        }
+        let answer = answer_re.captures_iter(article).last();
+        let example = answer
+            .as_ref()
+            .and_then(|a| {
+                let offset = a.get(0).unwrap().start();
+                blocks.iter().rev().find(|(start, _)| *start < offset)
+            })
+            .or(blocks.iter().find(|(_, index)| *index >= known))
+            .or(blocks.first())
+            .map(|(_, index)| *index);
+        let answer = answer.map(|c| unescape(&c[1]));
+        puzzle.parts.push(PuzzlePart {
+            example: example.or((!puzzle.examples.is_empty()).then_some(0)),
+            answer,
+        });
+    }
+    puzzle
+}
+
+/// Strips tags and decodes the entities that show up on puzzle pages.
+fn unescape(html: &str) -> String {
+    let tag_re = Regex::new(r"<[^>]*>").unwrap();
+    tag_re
+        .replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// File name of the `index`th example of `day`: `dayNNe.txt`, then
+/// `dayNNe2.txt`, `dayNNe3.txt`, ...
+pub fn example_file_name(day: u16, index: usize) -> String {
+    match index {
+        0 => format!("day{:02}e.txt", day),
+        _ => format!("day{:02}e{}.txt", day, index + 1),
+    }
+}
+
+/// Rust literal for an answer in a generated test, quoted unless numeric.
+pub fn answer_literal(answer: &str) -> String {
+    match answer.parse::<i128>() {
+        Ok(_) => answer.to_string(),
+        Err(_) => format!("{:?}", answer),
+    }
+}
+
+/// Return type of a part's solve functions: `usize`, or `String` if any of
+/// its example answers isn't numeric, so the generated tests compile.
+pub fn answer_type<'a>(answers: impl IntoIterator<Item = &'a str>) -> &'static str {
+    if answers.into_iter().all(|a| a.parse::<i128>().is_ok()) {
+        "usize"
+    } else {
+        "String"
+    }
+}
+
+/// Line diff between `before` and `after` in unified format, with
+/// `context` unchanged lines around each change.
+pub fn unified_diff(path: &str, before: Option<&str>, after: &str, context: usize) -> String {
+    let old: Vec<&str> = before.unwrap_or("").lines().collect();
+    let new: Vec<&str> = after.lines().collect();
+
+    // Longest common subsequence table, the files involved are small
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    // (tag, old line number, new line number, text)
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            ops.push((' ', i, j, old[i]));
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            ops.push(('+', i, j, new[j]));
+            j += 1;
+        } else {
+            ops.push(('-', i, j, old[i]));
+            i += 1;
+        }
+    }
+
+    let from = if before.is_some() {
+        format!("a/{}", path)
+    } else {
+        "/dev/null".to_string()
+    };
+    let mut out = format!("--- {}\n+++ b/{}\n", from, path);
+    let changed: Vec<usize> = (0..ops.len()).filter(|&k| ops[k].0 != ' ').collect();
+    let mut k = 0;
+    while k < changed.len() {
+        // Grow the hunk while the next change is within reach of its context
+        let start = changed[k].saturating_sub(context);
+        let mut end = changed[k];
+        while k + 1 < changed.len() && changed[k + 1] <= end + 2 * context + 1 {
+            k += 1;
+            end = changed[k];
+        }
+        let end = (end + context + 1).min(ops.len());
+        k += 1;
+
+        let hunk = &ops[start..end];
+        let old_len = hunk.iter().filter(|op| op.0 != '+').count();
+        let new_len = hunk.iter().filter(|op| op.0 != '-').count();
+        let old_start = if old_len == 0 {
+            hunk[0].1
+        } else {
+            hunk[0].1 + 1
+        };
+        let new_start = if new_len == 0 {
+            hunk[0].2
+        } else {
+            hunk[0].2 + 1
+        };
+        out.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            old_start, old_len, new_start, new_len
+        ));
+        for (tag, _, _, text) in hunk {
+            out.push_str(&format!("{}{}\n", tag, text));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR_MOD: &str = "\
+use crate::registry::{solution, Entry};
+
+pub mod day01;
+pub mod day03;
+
+pub static SOLUTIONS: &[Entry] = &[
+    solution!(2024, 1, 1, day01::solve_2024_01_01),
+    solution!(2024, 1, 2, day01::solve_2024_01_02),
+    solution!(2024, 3, 1, day03::solve_2024_03_01),
+    solution!(2024, 3, 2, day03::solve_2024_03_02),
+];
+";
+
+    #[test]
+    fn test_add_day() {
+        let after = add_day_entries(&add_day_module(YEAR_MOD, 2), 2024, 2, &[]);
+        assert_eq!(
+            after,
+            "\
+use crate::registry::{solution, Entry};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+pub static SOLUTIONS: &[Entry] = &[
+    solution!(2024, 1, 1, day01::solve_2024_01_01),
+    solution!(2024, 1, 2, day01::solve_2024_01_02),
+    solution!(2024, 2, 1, day02::solve_2024_02_01),
+    solution!(2024, 2, 2, day02::solve_2024_02_02),
+    solution!(2024, 3, 1, day03::solve_2024_03_01),
+    solution!(2024, 3, 2, day03::solve_2024_03_02),
+];
+"
+        );
+        assert_eq!(
+            add_day_entries(&add_day_module(&after, 2), 2024, 2, &[]),
+            after
+        );
+    }
+
+    #[test]
+    fn test_new_year() {
+        let year_mod = add_day_entries(&add_day_module(&new_year_module(), 1), 2026, 1, &[]);
+        assert_eq!(
+            year_mod,
+            "\
+use crate::registry::{solution, Entry};
+
+pub mod day01;
+
+pub static SOLUTIONS: &[Entry] = &[
+    solution!(2026, 1, 1, day01::solve_2026_01_01),
+    solution!(2026, 1, 2, day01::solve_2026_01_02),
+];
+"
+        );
+
+        let solutions = "\
+pub mod year2015;
+pub mod year2025;
+
+use crate::registry::Entry;
+
+pub static REGISTRY: &[&[Entry]] = &[
+    year2015::SOLUTIONS,
+    year2025::SOLUTIONS,
+];
+";
+        let after = add_year(solutions, 2024);
+        assert_eq!(
+            after,
+            "\
+pub mod year2015;
+pub mod year2024;
+pub mod year2025;
+
+use crate::registry::Entry;
+
+pub static REGISTRY: &[&[Entry]] = &[
+    year2015::SOLUTIONS,
+    year2024::SOLUTIONS,
+    year2025::SOLUTIONS,
+];
+"
+        );
+        assert_eq!(add_year(&after, 2024), after);
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let after = add_day_module(YEAR_MOD, 2);
+        assert_eq!(
+            unified_diff("mod.rs", Some(YEAR_MOD), &after, 1),
+            "--- a/mod.rs\n+++ b/mod.rs\n@@ -3,2 +3,3 @@\n pub mod day01;\n+pub mod day02;\n pub mod day03;\n"
+        );
+        assert_eq!(
+            unified_diff("new.rs", None, "a\nb\n", 3),
+            "--- /dev/null\n+++ b/new.rs\n@@ -0,0 +1,2 @@\n+a\n+b\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_puzzle() {
+        let html = r#"<main>
+<article class="day-desc"><h2>--- Day 5: If You Give A Seed &amp; Fertilizer ---</h2>
+<p>For example:</p>
+<pre><code>seeds: 79 14
+<em>a</em> -&gt; b
+</code></pre>
+<p>Seed <code>79</code> is at <code><em>82</em></code>, the lowest is <code><em>35</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>seeds: 79 14
+<em>a</em> -&gt; b
+</code></pre>
+<pre><code>other
+</code></pre>
+<p>The lowest is now <code><em>CMZ</em></code>.</p>
+</article>
+</main>"#;
+        assert_eq!(
+            parse_puzzle(html),
+            Puzzle {
+                title: Some("If You Give A Seed & Fertilizer".to_string()),
+                examples: vec!["seeds: 79 14\na -> b\n".to_string(), "other\n".to_string()],
+                parts: vec![
+                    PuzzlePart {
+                        example: Some(0),
+                        answer: Some("35".to_string()),
+                    },
+                    PuzzlePart {
+                        example: Some(1),
+                        answer: Some("CMZ".to_string()),
+                    },
+                ],
+            }
+        );
+        assert_eq!(example_file_name(5, 1), "day05e2.txt");
+        assert_eq!(answer_literal("35"), "35");
+        assert_eq!(answer_literal("CMZ"), "\"CMZ\"");
+        assert_eq!(answer_type(["35", "46"]), "usize");
+        assert_eq!(answer_type(["35", "CMZ"]), "String");
+    }
+
+    #[test]
+    fn test_params() {
+        let moves: Param = "moves:usize=64".parse().unwrap();
+        assert_eq!(
+            moves,
+            Param {
+                name: "moves".to_string(),
+                ty: "usize".to_string(),
+                value: Some("64".to_string()),
+            }
+        );
+        let factor: Param = "factor: u64".parse().unwrap();
+        assert_eq!(factor.argument(), "Default::default()");
+        assert!("moves".parse::<Param>().is_err());
+        assert!("1x:usize".parse::<Param>().is_err());
+
+        let year_mod = add_day_entries(YEAR_MOD, 2024, 21, &[moves.argument()]);
+        assert!(year_mod.contains("    solution!(2024, 21, 2, day21::solve_2024_21_02, 64),\n];"));
+    }
+}
@@ -0,0 +1,75 @@
+use std::fmt::Debug;
+use std::str::FromStr;
+
+use crate::answer::Answer;
+
+/// A day split into its parsing and solving phases, so callers can parse
+/// the input once and reuse it for both parts.
+///
+/// Implemented on a unit struct per day (e.g. `Day05`); the day's
+/// `solve_*` functions are thin wrappers over it.
+pub trait Solution {
+    type Parsed;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed>;
+    fn part1(parsed: &Self::Parsed) -> anyhow::Result<Self::Part1>;
+    fn part2(parsed: &Self::Parsed) -> anyhow::Result<Self::Part2>;
+
+    /// Parses `input` and solves both parts.
+    fn solve(input: &str) -> anyhow::Result<(Answer, Answer)> {
+        let parsed = Self::parse(input)?;
+        Ok((Self::part1(&parsed)?.into(), Self::part2(&parsed)?.into()))
+    }
+}
+
+/// Parses `input` with a `FromStr` impl whose error type only implements
+/// `Debug`, as most of the puzzle parsers do.
+pub fn parse_with<T>(input: &str) -> anyhow::Result<T>
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    input
+        .parse::<T>()
+        .map_err(|e| anyhow::anyhow!("Failed to parse input: {:?}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sums;
+
+    impl Solution for Sums {
+        type Parsed = Vec<usize>;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(input: &str) -> anyhow::Result<Vec<usize>> {
+            input.lines().map(parse_with).collect()
+        }
+
+        fn part1(values: &Vec<usize>) -> anyhow::Result<usize> {
+            Ok(values.iter().sum())
+        }
+
+        fn part2(values: &Vec<usize>) -> anyhow::Result<usize> {
+            Ok(values.iter().product())
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let (part1, part2) = Sums::solve("2\n3\n4").unwrap();
+        assert_eq!(part1, Answer::Int(9));
+        assert_eq!(part2, Answer::Int(24));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Sums::solve("2\nx").unwrap_err();
+        assert!(err.to_string().starts_with("Failed to parse input"));
+    }
+}
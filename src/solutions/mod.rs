@@ -4,3 +4,18 @@ pub mod year2022;
 pub mod year2023;
 pub mod year2024;
 pub mod year2025;
+
+use crate::registry::{Entry, Phased};
+
+/// Per-year solution tables, see [`crate::registry`].
+pub static REGISTRY: &[&[Entry]] = &[
+    year2015::SOLUTIONS,
+    year2021::SOLUTIONS,
+    year2022::SOLUTIONS,
+    year2023::SOLUTIONS,
+    year2024::SOLUTIONS,
+    year2025::SOLUTIONS,
+];
+
+/// Per-year tables of the days that implement [`crate::solution::Solution`].
+pub static PHASED: &[&[Phased]] = &[year2023::PHASED];
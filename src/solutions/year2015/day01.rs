@@ -1,25 +1,18 @@
+pub fn solve_2015_01_01(input: &str) -> Option<i32> {
+    Some(input.trim_end().chars().fold(0, |acc, c| match c {
+        '(' => acc + 1,
+        ')' => acc - 1,
+        _ => unreachable!(),
+    }))
+}
+
 pub fn solution_2015_01_01(filepath: String) -> Option<i32> {
-    Some(
-        std::fs::read_to_string(filepath)
-            .expect("Invalid file.")
-            .trim_end()
-            .chars()
-            .fold(0, |acc, c| match c {
-                '(' => acc + 1,
-                ')' => acc - 1,
-                _ => unreachable!(),
-            }),
-    )
+    solve_2015_01_01(&std::fs::read_to_string(filepath).expect("Invalid file."))
 }
 
-pub fn solution_2015_01_02(filepath: String) -> Option<usize> {
+pub fn solve_2015_01_02(input: &str) -> Option<usize> {
     let mut floor = 0;
-    for (i, v) in std::fs::read_to_string(filepath)
-        .expect("Invalid file.")
-        .trim_end()
-        .chars()
-        .enumerate()
-    {
+    for (i, v) in input.trim_end().chars().enumerate() {
         match v {
             '(' => floor += 1,
             ')' => floor -= 1,
@@ -32,6 +25,10 @@ pub fn solution_2015_01_02(filepath: String) -> Option<usize> {
     None
 }
 
+pub fn solution_2015_01_02(filepath: String) -> Option<usize> {
+    solve_2015_01_02(&std::fs::read_to_string(filepath).expect("Invalid file."))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
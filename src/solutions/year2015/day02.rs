@@ -15,24 +15,30 @@ fn calculate_ribbon(vals: &Vec<usize>) -> usize {
     (2 * (vals[0] + vals[1])) + (vals[0] * vals[1] * vals[2])
 }
 
-pub fn solution_2015_02_01(filepath: String) -> Option<usize> {
+pub fn solve_2015_02_01(input: &str) -> Option<usize> {
     Some(
-        std::fs::read_to_string(filepath)
-            .expect("Invalid file.")
+        input
             .lines()
             .fold(0, |acc, l| acc + calculate_paper(&parse_dimensions(l))),
     )
 }
 
-pub fn solution_2015_02_02(filepath: String) -> Option<usize> {
+pub fn solution_2015_02_01(filepath: String) -> Option<usize> {
+    solve_2015_02_01(&std::fs::read_to_string(filepath).expect("Invalid file."))
+}
+
+pub fn solve_2015_02_02(input: &str) -> Option<usize> {
     Some(
-        std::fs::read_to_string(filepath)
-            .expect("Invalid file.")
+        input
             .lines()
             .fold(0, |acc, l| acc + calculate_ribbon(&parse_dimensions(l))),
     )
 }
 
+pub fn solution_2015_02_02(filepath: String) -> Option<usize> {
+    solve_2015_02_02(&std::fs::read_to_string(filepath).expect("Invalid file."))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
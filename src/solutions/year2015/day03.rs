@@ -27,36 +27,35 @@ impl Walker {
     }
 }
 
-pub fn solution_2015_03_01(filepath: String) -> Option<usize> {
+pub fn solve_2015_03_01(input: &str) -> Option<usize> {
     let mut visited = HashSet::new();
     let mut santa = Walker::new();
     visited.insert(santa.key());
 
-    for dir in std::fs::read_to_string(filepath)
-        .expect("Invalid file.")
-        .trim_end()
-        .chars()
-    {
+    for dir in input.trim_end().chars() {
         visited.insert(santa.walk(&dir));
     }
     Some(visited.len())
 }
 
-pub fn solution_2015_03_02(filepath: String) -> Option<usize> {
+pub fn solution_2015_03_01(filepath: String) -> Option<usize> {
+    solve_2015_03_01(&std::fs::read_to_string(filepath).expect("Invalid file."))
+}
+
+pub fn solve_2015_03_02(input: &str) -> Option<usize> {
     let mut visited = HashSet::new();
     let mut santas = [Walker::new(), Walker::new()];
     visited.insert(santas[0].key());
 
-    for (i, dir) in std::fs::read_to_string(filepath)
-        .expect("Invalid file.")
-        .trim_end()
-        .chars()
-        .enumerate()
-    {
+    for (i, dir) in input.trim_end().chars().enumerate() {
         visited.insert(santas[i % 2].walk(&dir));
     }
     Some(visited.len())
 }
+
+pub fn solution_2015_03_02(filepath: String) -> Option<usize> {
+    solve_2015_03_02(&std::fs::read_to_string(filepath).expect("Invalid file."))
+}
 #[cfg(test)]
 mod test {
     use super::*;
@@ -20,19 +20,22 @@ fn is_nice_advanced(s: &str) -> bool {
     has_repeated_pair && has_sandwiched_letter
 }
 
+pub fn solve_2015_05_01(input: &str) -> Result<usize, Box<dyn std::error::Error>> {
+    let nice_count = input.lines().filter(|line| is_nice_naive(line)).count();
+    Ok(nice_count)
+}
+
 pub fn solution_2015_05_01(filepath: String) -> Result<usize, Box<dyn std::error::Error>> {
-    let content = std::fs::read_to_string(filepath).expect("Invalid file.");
-    let nice_count = content.lines().filter(|line| is_nice_naive(line)).count();
+    solve_2015_05_01(&std::fs::read_to_string(filepath).expect("Invalid file."))
+}
+
+pub fn solve_2015_05_02(input: &str) -> Result<usize, Box<dyn std::error::Error>> {
+    let nice_count = input.lines().filter(|line| is_nice_advanced(line)).count();
     Ok(nice_count)
 }
 
 pub fn solution_2015_05_02(filepath: String) -> Result<usize, Box<dyn std::error::Error>> {
-    let content = std::fs::read_to_string(filepath).expect("Invalid file.");
-    let nice_count = content
-        .lines()
-        .filter(|line| is_nice_advanced(line))
-        .count();
-    Ok(nice_count)
+    solve_2015_05_02(&std::fs::read_to_string(filepath).expect("Invalid file."))
 }
 
 #[cfg(test)]
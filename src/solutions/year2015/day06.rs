@@ -96,9 +96,9 @@ impl Instruction {
     }
 }
 
-pub fn solution_2015_06_01(filepath: String) -> Result<usize, Box<dyn std::error::Error>> {
+pub fn solve_2015_06_01(input: &str) -> Result<usize, Box<dyn std::error::Error>> {
     let mut grid = HashMap::new();
-    std::fs::read_to_string(filepath)?
+    input
         .trim_end()
         .lines()
         .filter_map(Instruction::from_str)
@@ -109,9 +109,13 @@ pub fn solution_2015_06_01(filepath: String) -> Result<usize, Box<dyn std::error
     Ok(count)
 }
 
-pub fn solution_2015_06_02(filepath: String) -> Result<usize, Box<dyn std::error::Error>> {
+pub fn solution_2015_06_01(filepath: String) -> Result<usize, Box<dyn std::error::Error>> {
+    solve_2015_06_01(&std::fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2015_06_02(input: &str) -> Result<usize, Box<dyn std::error::Error>> {
     let mut grid = HashMap::new();
-    std::fs::read_to_string(filepath)?
+    input
         .trim_end()
         .lines()
         .filter_map(Instruction::from_str)
@@ -122,6 +126,10 @@ pub fn solution_2015_06_02(filepath: String) -> Result<usize, Box<dyn std::error
     Ok(count)
 }
 
+pub fn solution_2015_06_02(filepath: String) -> Result<usize, Box<dyn std::error::Error>> {
+    solve_2015_06_02(&std::fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
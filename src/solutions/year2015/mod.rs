@@ -1,6 +1,23 @@
+use crate::registry::{solution, Entry};
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
 pub mod day04;
 pub mod day05;
 pub mod day06;
+
+pub static SOLUTIONS: &[Entry] = &[
+    solution!(2015, 1, 1, day01::solve_2015_01_01),
+    solution!(2015, 1, 2, day01::solve_2015_01_02),
+    solution!(2015, 2, 1, day02::solve_2015_02_01),
+    solution!(2015, 2, 2, day02::solve_2015_02_02),
+    solution!(2015, 3, 1, day03::solve_2015_03_01),
+    solution!(2015, 3, 2, day03::solve_2015_03_02),
+    solution!(2015, 4, 1, day04::solve_2015_04_01),
+    solution!(2015, 4, 2, day04::solve_2015_04_02),
+    solution!(2015, 5, 1, day05::solve_2015_05_01),
+    solution!(2015, 5, 2, day05::solve_2015_05_02),
+    solution!(2015, 6, 1, day06::solve_2015_06_01),
+    solution!(2015, 6, 2, day06::solve_2015_06_02),
+];
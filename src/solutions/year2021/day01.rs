@@ -1,7 +1,7 @@
 // Advent of Code 2021 - Day 01
 
-pub fn solution_2021_01_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let content: Vec<i64> = std::fs::read_to_string(filepath)?
+pub fn solve_2021_01_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let content: Vec<i64> = input
         .trim()
         .lines()
         .map(|line| line.parse::<i64>())
@@ -18,8 +18,12 @@ pub fn solution_2021_01_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(count)
 }
 
-pub fn solution_2021_01_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let content: Vec<i64> = std::fs::read_to_string(filepath)?
+pub fn solution_2021_01_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_01_01(&std::fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_01_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let content: Vec<i64> = input
         .trim()
         .lines()
         .map(|line| line.parse::<i64>())
@@ -37,6 +41,10 @@ pub fn solution_2021_01_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(count)
 }
 
+pub fn solution_2021_01_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_01_02(&std::fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,7 +1,7 @@
 // Advent of Code 2021 - Day 02
 
-pub fn solution_2021_02_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let instructions: Vec<String> = std::fs::read_to_string(filepath)?
+pub fn solve_2021_02_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let instructions: Vec<String> = input
         .trim()
         .lines()
         .map(|s| s.to_string())
@@ -25,8 +25,12 @@ pub fn solution_2021_02_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(distance * depth)
 }
 
-pub fn solution_2021_02_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let instructions: Vec<String> = std::fs::read_to_string(filepath)?
+pub fn solution_2021_02_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_02_01(&std::fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_02_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let instructions: Vec<String> = input
         .trim()
         .lines()
         .map(|s| s.to_string())
@@ -54,6 +58,10 @@ pub fn solution_2021_02_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(distance * depth)
 }
 
+pub fn solution_2021_02_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_02_02(&std::fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
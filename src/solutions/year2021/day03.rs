@@ -28,8 +28,8 @@ fn reducer(bins: &[String], idx: usize, least: bool) -> String {
     }
 }
 
-pub fn solution_2021_03_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let bins: Vec<String> = std::fs::read_to_string(filepath)?
+pub fn solve_2021_03_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let bins: Vec<String> = input
         .trim()
         .lines()
         .map(|s| s.to_string())
@@ -65,8 +65,12 @@ pub fn solution_2021_03_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(gamma_int * epsilon_int)
 }
 
-pub fn solution_2021_03_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let bins: Vec<String> = std::fs::read_to_string(filepath)?
+pub fn solution_2021_03_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_03_01(&std::fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_03_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let bins: Vec<String> = input
         .trim()
         .lines()
         .map(|s| s.to_string())
@@ -80,6 +84,10 @@ pub fn solution_2021_03_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(o2_int * co2_int)
 }
 
+pub fn solution_2021_03_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_03_02(&std::fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
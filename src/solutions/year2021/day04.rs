@@ -84,11 +84,8 @@ impl Board {
     }
 }
 
-pub fn solution_2021_04_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let mut data: Vec<String> = fs::read_to_string(filepath)?
-        .split("\n\n")
-        .map(|d| d.to_string())
-        .collect();
+pub fn solve_2021_04_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let mut data: Vec<String> = input.split("\n\n").map(|d| d.to_string()).collect();
     let inputs: Vec<u64> = data
         .remove(0)
         .split(',')
@@ -113,11 +110,12 @@ pub fn solution_2021_04_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(output as i64)
 }
 
-pub fn solution_2021_04_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let mut data: Vec<String> = fs::read_to_string(filepath)?
-        .split("\n\n")
-        .map(|d| d.to_string())
-        .collect();
+pub fn solution_2021_04_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_04_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_04_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let mut data: Vec<String> = input.split("\n\n").map(|d| d.to_string()).collect();
     let inputs: Vec<u64> = data
         .remove(0)
         .split(',')
@@ -141,6 +139,10 @@ pub fn solution_2021_04_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(output as i64)
 }
 
+pub fn solution_2021_04_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_04_02(&fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,11 +100,8 @@ impl Line {
     }
 }
 
-pub fn solution_2021_05_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let lines: Vec<String> = fs::read_to_string(filepath)?
-        .split('\n')
-        .map(|s| s.to_string())
-        .collect();
+pub fn solve_2021_05_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let lines: Vec<String> = input.split('\n').map(|s| s.to_string()).collect();
     let mut cloud_map = HashMap::new();
     for line in lines.iter() {
         let line = Line::from_arrowed_pair(line);
@@ -119,11 +116,12 @@ pub fn solution_2021_05_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(count as i64)
 }
 
-pub fn solution_2021_05_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let lines: Vec<String> = fs::read_to_string(filepath)?
-        .split('\n')
-        .map(|s| s.to_string())
-        .collect();
+pub fn solution_2021_05_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_05_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_05_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let lines: Vec<String> = input.split('\n').map(|s| s.to_string()).collect();
     let mut cloud_map = HashMap::new();
     for line in lines.iter() {
         let line = Line::from_arrowed_pair(line);
@@ -138,6 +136,10 @@ pub fn solution_2021_05_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(count as i64)
 }
 
+pub fn solution_2021_05_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_05_02(&fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,8 +2,8 @@
 
 use std::fs;
 
-pub fn solution_2021_06_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let data: Vec<usize> = fs::read_to_string(filepath)?
+pub fn solve_2021_06_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let data: Vec<usize> = input
         .split(',')
         .map(|v| v.parse::<usize>().unwrap())
         .collect();
@@ -18,8 +18,12 @@ pub fn solution_2021_06_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(result as i64)
 }
 
-pub fn solution_2021_06_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let data: Vec<usize> = fs::read_to_string(filepath)?
+pub fn solution_2021_06_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_06_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_06_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let data: Vec<usize> = input
         .split(',')
         .map(|v| v.parse::<usize>().unwrap())
         .collect();
@@ -34,6 +38,10 @@ pub fn solution_2021_06_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(result as i64)
 }
 
+pub fn solution_2021_06_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_06_02(&fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,8 +2,8 @@
 
 use std::fs;
 
-pub fn solution_2021_07_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let mut input: Vec<i64> = fs::read_to_string(filepath)?
+pub fn solve_2021_07_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let mut input: Vec<i64> = input
         .split(',')
         .map(|v| v.parse::<i64>().unwrap())
         .collect();
@@ -16,8 +16,12 @@ pub fn solution_2021_07_01(filepath: String) -> Result<i64, Box<dyn std::error::
     }))
 }
 
-pub fn solution_2021_07_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let input: Vec<f64> = fs::read_to_string(filepath)?
+pub fn solution_2021_07_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_07_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_07_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let input: Vec<f64> = input
         .split(',')
         .map(|v| v.parse::<f64>().unwrap())
         .collect();
@@ -46,6 +50,10 @@ pub fn solution_2021_07_02(filepath: String) -> Result<i64, Box<dyn std::error::
     }
 }
 
+pub fn solution_2021_07_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_07_02(&fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -50,8 +50,8 @@ fn count_unique(data: Vec<String>) -> usize {
     })
 }
 
-pub fn solution_2021_08_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let data: Vec<String> = fs::read_to_string(filepath)?
+pub fn solve_2021_08_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let data: Vec<String> = input
         .split('\n')
         .map(|s| s.to_string())
         .collect();
@@ -61,6 +61,10 @@ pub fn solution_2021_08_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(result as i64)
 }
 
+pub fn solution_2021_08_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_08_01(&fs::read_to_string(filepath)?)
+}
+
 fn decode_pattern(data: (Vec<String>, Vec<String>)) -> usize {
     let (clues, to_decode) = data;
     let (mut one, mut two, mut four, mut five, mut six, mut seven, mut eight, mut nine) = (
@@ -150,8 +154,8 @@ fn decode_pattern(data: (Vec<String>, Vec<String>)) -> usize {
         .unwrap()
 }
 
-pub fn solution_2021_08_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let data: Vec<String> = fs::read_to_string(filepath)?
+pub fn solve_2021_08_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let data: Vec<String> = input
         .split('\n')
         .map(|s| s.to_string())
         .collect();
@@ -161,6 +165,10 @@ pub fn solution_2021_08_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(result as i64)
 }
 
+pub fn solution_2021_08_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_08_02(&fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,8 +30,8 @@ fn is_lowest(val: &usize, adjacents: &Vec<usize>) -> bool {
     adjacents.iter().all(|x| x > val)
 }
 
-pub fn solution_2021_09_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let data: Vec<Vec<usize>> = fs::read_to_string(filepath)?
+pub fn solve_2021_09_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let data: Vec<Vec<usize>> = input
         .split('\n')
         .map(|line| {
             line.chars()
@@ -58,6 +58,10 @@ pub fn solution_2021_09_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(risks as i64)
 }
 
+pub fn solution_2021_09_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_09_01(&fs::read_to_string(filepath)?)
+}
+
 fn get_basin(
     row: usize,
     col: usize,
@@ -98,8 +102,8 @@ fn get_basin(
     }
 }
 
-pub fn solution_2021_09_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let data: Vec<Vec<usize>> = fs::read_to_string(filepath)?
+pub fn solve_2021_09_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let data: Vec<Vec<usize>> = input
         .split('\n')
         .map(|line| {
             line.chars()
@@ -121,6 +125,10 @@ pub fn solution_2021_09_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok((basins[0] * basins[1] * basins[2]) as i64)
 }
 
+pub fn solution_2021_09_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_09_02(&fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -42,11 +42,8 @@ fn find_corruptions(instruction: &str) -> (usize, usize) {
     (0, auto_complete(&stack))
 }
 
-pub fn solution_2021_10_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let instructions: Vec<String> = fs::read_to_string(filepath)?
-        .split('\n')
-        .map(|s| s.to_string())
-        .collect();
+pub fn solve_2021_10_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let instructions: Vec<String> = input.split('\n').map(|s| s.to_string()).collect();
     let res = instructions.iter().fold(0, |acc, val| {
         let (corrupt, _) = find_corruptions(val);
         acc + corrupt
@@ -54,11 +51,12 @@ pub fn solution_2021_10_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(res as i64)
 }
 
-pub fn solution_2021_10_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let instructions: Vec<String> = fs::read_to_string(filepath)?
-        .split('\n')
-        .map(|s| s.to_string())
-        .collect();
+pub fn solution_2021_10_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_10_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_10_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let instructions: Vec<String> = input.split('\n').map(|s| s.to_string()).collect();
     let mut auto_completes: Vec<usize> = vec![];
     for val in instructions.iter() {
         let (corrupt, auto_complete) = find_corruptions(val);
@@ -70,6 +68,10 @@ pub fn solution_2021_10_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(auto_completes[auto_completes.len() / 2] as i64)
 }
 
+pub fn solution_2021_10_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_10_02(&fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,11 +69,8 @@ fn flash(octopuses: &mut Vec<isize>, size: isize) -> usize {
     flashes
 }
 
-pub fn solution_2021_11_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let data: Vec<String> = fs::read_to_string(filepath)?
-        .lines()
-        .map(|s| s.to_string())
-        .collect();
+pub fn solve_2021_11_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let data: Vec<String> = input.lines().map(|s| s.to_string()).collect();
     let size = data.len() as isize;
     let mut octopuses: Vec<isize> = data
         .iter()
@@ -87,11 +84,12 @@ pub fn solution_2021_11_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(res as i64)
 }
 
-pub fn solution_2021_11_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let data: Vec<String> = fs::read_to_string(filepath)?
-        .lines()
-        .map(|s| s.to_string())
-        .collect();
+pub fn solution_2021_11_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_11_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_11_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let data: Vec<String> = input.lines().map(|s| s.to_string()).collect();
     let size = data.len() as isize;
     let mut octopuses: Vec<isize> = data
         .iter()
@@ -109,6 +107,10 @@ pub fn solution_2021_11_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(steps as i64)
 }
 
+pub fn solution_2021_11_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_11_02(&fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
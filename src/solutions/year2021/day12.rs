@@ -178,11 +178,8 @@ fn traverse_part2(
     }
 }
 
-pub fn solution_2021_12_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let paths: Vec<String> = fs::read_to_string(filepath)?
-        .lines()
-        .map(|s| s.to_string())
-        .collect();
+pub fn solve_2021_12_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let paths: Vec<String> = input.lines().map(|s| s.to_string()).collect();
     let nodes = create_nodes_part1(&paths);
     let visit_map: HashMap<String, usize> = HashMap::new();
     let values = traverse_part1(&nodes, &"start".to_string(), &visit_map);
@@ -193,11 +190,12 @@ pub fn solution_2021_12_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(count as i64)
 }
 
-pub fn solution_2021_12_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let paths: Vec<String> = fs::read_to_string(filepath)?
-        .lines()
-        .map(|s| s.to_string())
-        .collect();
+pub fn solution_2021_12_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_12_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_12_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let paths: Vec<String> = input.lines().map(|s| s.to_string()).collect();
     let (nodes, small_caves) = create_nodes_part2(&paths);
     let mut results: Vec<String> = vec![];
     for cave in small_caves {
@@ -215,6 +213,10 @@ pub fn solution_2021_12_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(results.len() as i64)
 }
 
+pub fn solution_2021_12_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_12_02(&fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
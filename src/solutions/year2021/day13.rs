@@ -2,12 +2,8 @@
 
 use std::fs;
 
-fn get_input(file_path: String) -> (Vec<Vec<usize>>, Vec<Vec<String>>) {
-    let content: Vec<String> = fs::read_to_string(file_path)
-        .expect("Invalid File")
-        .split("\n\n")
-        .map(|x| x.to_string())
-        .collect();
+fn get_input(input: &str) -> (Vec<Vec<usize>>, Vec<Vec<String>>) {
+    let content: Vec<String> = input.split("\n\n").map(|x| x.to_string()).collect();
     let points: Vec<Vec<usize>> = content[0]
         .split('\n')
         .map(|x| {
@@ -77,8 +73,8 @@ fn fold_y(page: &Vec<Vec<char>>, pos: usize) -> Vec<Vec<char>> {
     top
 }
 
-pub fn solution_2021_13_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let (points, instructions) = get_input(filepath);
+pub fn solve_2021_13_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let (points, instructions) = get_input(input);
     let mut page = create_page(&points);
     match instructions[0][0].as_str() {
         "fold along y" => page = fold_y(&page, instructions[0][1].parse::<usize>().unwrap()),
@@ -93,8 +89,12 @@ pub fn solution_2021_13_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(count)
 }
 
-pub fn solution_2021_13_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let (points, instructions) = get_input(filepath);
+pub fn solution_2021_13_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_13_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_13_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let (points, instructions) = get_input(input);
     let mut page = create_page(&points);
     for instruction in instructions.iter() {
         match instruction[0].as_str() {
@@ -111,6 +111,10 @@ pub fn solution_2021_13_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(0)
 }
 
+pub fn solution_2021_13_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_13_02(&fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,12 +77,8 @@ fn count_items(template: &Vec<char>) -> HashMap<char, usize> {
     result
 }
 
-fn solve(filepath: String, limit: usize) -> usize {
-    let data: Vec<String> = fs::read_to_string(filepath)
-        .expect("Invalid File")
-        .lines()
-        .map(|s| s.to_string())
-        .collect();
+fn solve(input: &str, limit: usize) -> usize {
+    let data: Vec<String> = input.lines().map(|s| s.to_string()).collect();
     let template: Vec<char> = data[0].chars().collect();
     let rules = create_rules(&data[2..]);
     let mut cache: HashMap<String, HashMap<char, usize>> = HashMap::new();
@@ -96,12 +92,20 @@ fn solve(filepath: String, limit: usize) -> usize {
     counts[counts.len() - 1] - counts[0]
 }
 
+pub fn solve_2021_14_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    Ok(solve(input, 10) as i64)
+}
+
 pub fn solution_2021_14_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    Ok(solve(filepath, 10) as i64)
+    solve_2021_14_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_14_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    Ok(solve(input, 40) as i64)
 }
 
 pub fn solution_2021_14_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    Ok(solve(filepath, 40) as i64)
+    solve_2021_14_02(&fs::read_to_string(filepath)?)
 }
 
 #[cfg(test)]
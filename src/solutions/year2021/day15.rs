@@ -94,8 +94,8 @@ fn create_full_map(cavern: &Vec<Vec<usize>>) -> Vec<Vec<usize>> {
     res
 }
 
-pub fn solution_2021_15_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let data: Vec<Vec<usize>> = fs::read_to_string(filepath)?
+pub fn solve_2021_15_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let data: Vec<Vec<usize>> = input
         .lines()
         .map(|line| {
             line.chars()
@@ -106,8 +106,12 @@ pub fn solution_2021_15_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(traverse(&data) as i64)
 }
 
-pub fn solution_2021_15_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let data: Vec<Vec<usize>> = fs::read_to_string(filepath)?
+pub fn solution_2021_15_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_15_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_15_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let data: Vec<Vec<usize>> = input
         .lines()
         .map(|line| {
             line.chars()
@@ -119,6 +123,10 @@ pub fn solution_2021_15_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(traverse(&map) as i64)
 }
 
+pub fn solution_2021_15_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_15_02(&fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
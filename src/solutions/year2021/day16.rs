@@ -196,18 +196,24 @@ fn solve(input: &String) -> (usize, usize) {
     (result.value, sum)
 }
 
-pub fn solution_2021_16_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let input = std::fs::read_to_string(filepath)?;
+pub fn solve_2021_16_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
     let (_, version_sum) = solve(&input.trim().to_string());
     Ok(version_sum as i64)
 }
 
-pub fn solution_2021_16_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let input = std::fs::read_to_string(filepath)?;
+pub fn solution_2021_16_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_16_01(&std::fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_16_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
     let (value, _) = solve(&input.trim().to_string());
     Ok(value as i64)
 }
 
+pub fn solution_2021_16_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_16_02(&std::fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,16 +128,24 @@ fn solve(x: (isize, isize), y: (isize, isize)) -> (isize, usize) {
     (highest, count)
 }
 
-pub fn solution_2021_17_01(_filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+pub fn solve_2021_17_01(_input: &str) -> Result<i64, Box<dyn std::error::Error>> {
     let (highest, _) = solve((253, 280), (-73, -46));
     Ok(highest as i64)
 }
 
-pub fn solution_2021_17_02(_filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+pub fn solution_2021_17_01(_filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_17_01("")
+}
+
+pub fn solve_2021_17_02(_input: &str) -> Result<i64, Box<dyn std::error::Error>> {
     let (_, count) = solve((253, 280), (-73, -46));
     Ok(count as i64)
 }
 
+pub fn solution_2021_17_02(_filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_17_02("")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
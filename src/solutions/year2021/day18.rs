@@ -175,8 +175,8 @@ impl Add for ListItem {
     }
 }
 
-pub fn solution_2021_18_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let mut numbers: Vec<ListItem> = fs::read_to_string(filepath)?
+pub fn solve_2021_18_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let mut numbers: Vec<ListItem> = input
         .lines()
         .filter_map(|x| serde_json::from_str::<ListItem>(x).ok())
         .collect();
@@ -186,8 +186,12 @@ pub fn solution_2021_18_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(res.magnitude() as i64)
 }
 
-pub fn solution_2021_18_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let numbers: Vec<ListItem> = fs::read_to_string(filepath)?
+pub fn solution_2021_18_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_18_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_18_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let numbers: Vec<ListItem> = input
         .lines()
         .filter_map(|x| serde_json::from_str::<ListItem>(x).ok())
         .collect();
@@ -207,6 +211,10 @@ pub fn solution_2021_18_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(*results.iter().max().unwrap() as i64)
 }
 
+pub fn solution_2021_18_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_18_02(&fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,15 +143,17 @@ struct ScannerReading {
     beacon_distance_set: FxHashSet<usize>,
 }
 
-pub fn solution_2021_19_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let input = fs::read_to_string(filepath)?;
+pub fn solve_2021_19_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
     let mut plane = parse(&input.trim_end());
     plane.merge_all_readings();
     Ok(plane.beacon_locations.len() as i64)
 }
 
-pub fn solution_2021_19_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let input = fs::read_to_string(filepath)?;
+pub fn solution_2021_19_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_19_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_19_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
     let mut plane = parse(&input.trim_end());
     plane.merge_all_readings();
     let max_dist = plane
@@ -164,6 +166,10 @@ pub fn solution_2021_19_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(max_dist as i64)
 }
 
+pub fn solution_2021_19_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_19_02(&fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
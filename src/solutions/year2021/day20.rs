@@ -46,12 +46,8 @@ fn count(data: &Vec<Vec<char>>) -> usize {
     data.iter().flatten().filter(|&x| x == &'1').count()
 }
 
-fn solve(filepath: String, limit: usize) -> usize {
-    let data: Vec<String> = fs::read_to_string(filepath)
-        .expect("Invalid File")
-        .split("\n\n")
-        .map(|x| x.to_string())
-        .collect();
+fn solve(input: &str, limit: usize) -> usize {
+    let data: Vec<String> = input.split("\n\n").map(|x| x.to_string()).collect();
     let lookup: Vec<char> = data[0]
         .chars()
         .map(|c| match c {
@@ -87,12 +83,20 @@ fn solve(filepath: String, limit: usize) -> usize {
     count(&image)
 }
 
+pub fn solve_2021_20_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    Ok(solve(input, 2) as i64)
+}
+
 pub fn solution_2021_20_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    Ok(solve(filepath, 2) as i64)
+    solve_2021_20_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_20_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    Ok(solve(input, 50) as i64)
 }
 
 pub fn solution_2021_20_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    Ok(solve(filepath, 50) as i64)
+    solve_2021_20_02(&fs::read_to_string(filepath)?)
 }
 
 #[cfg(test)]
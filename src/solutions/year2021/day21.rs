@@ -77,7 +77,7 @@ fn diracs_dice_game(
     }
 }
 
-pub fn solution_2021_21_01(_filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+pub fn solve_2021_21_01(_input: &str) -> Result<i64, Box<dyn std::error::Error>> {
     let positions = vec![8, 9];
     let players: Vec<Player> = positions
         .iter()
@@ -90,7 +90,11 @@ pub fn solution_2021_21_01(_filepath: String) -> Result<i64, Box<dyn std::error:
     Ok(res as i64)
 }
 
-pub fn solution_2021_21_02(_filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+pub fn solution_2021_21_01(_filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_21_01("")
+}
+
+pub fn solve_2021_21_02(_input: &str) -> Result<i64, Box<dyn std::error::Error>> {
     let positions = vec![8, 9];
     let players: Vec<Player> = positions
         .iter()
@@ -104,6 +108,10 @@ pub fn solution_2021_21_02(_filepath: String) -> Result<i64, Box<dyn std::error:
     Ok(res.0.max(res.1) as i64)
 }
 
+pub fn solution_2021_21_02(_filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_21_02("")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -64,8 +64,8 @@ impl Instruction {
     }
 }
 
-pub fn solution_2021_22_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let instructions: Vec<String> = fs::read_to_string(filepath)?
+pub fn solve_2021_22_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let instructions: Vec<String> = input
         .lines()
         .map(|s| s.to_string())
         .collect();
@@ -86,8 +86,12 @@ pub fn solution_2021_22_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(reactor.values().filter(|v| v == &&true).count() as i64)
 }
 
-pub fn solution_2021_22_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let instructions: Vec<String> = fs::read_to_string(filepath)?
+pub fn solution_2021_22_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_22_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_22_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let instructions: Vec<String> = input
         .lines()
         .map(|s| s.to_string())
         .collect();
@@ -120,6 +124,10 @@ pub fn solution_2021_22_02(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(count as i64)
 }
 
+pub fn solution_2021_22_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_22_02(&fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
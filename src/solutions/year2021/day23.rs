@@ -130,20 +130,26 @@ fn parse_state<const N: usize>(s: &str) -> [u8; N] {
     state
 }
 
-pub fn solution_2021_23_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let input = fs::read_to_string(filepath)?;
-    let result = astar_fuel_cost(parse_state::<{ 11 + 2 * 4 }>(&input));
+pub fn solve_2021_23_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let result = astar_fuel_cost(parse_state::<{ 11 + 2 * 4 }>(input));
     Ok(result.unwrap() as i64)
 }
 
-pub fn solution_2021_23_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let input = fs::read_to_string(filepath)?;
+pub fn solution_2021_23_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_23_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_23_02(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
     let mut part2_input = input.lines().collect_vec();
     part2_input.splice(3..3, ["#D#C#B#A#", "#D#B#A#C#"]);
     let result = astar_fuel_cost(parse_state::<{ 11 + 4 * 4 }>(&part2_input.join("")));
     Ok(result.unwrap() as i64)
 }
 
+pub fn solution_2021_23_02(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_23_02(&fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
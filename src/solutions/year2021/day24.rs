@@ -2,18 +2,26 @@
 // Note: Day 24 requires manual analysis of the ALU program
 // The solutions are hard-coded based on analysis
 
-pub fn solution_2021_24_01(_filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+pub fn solve_2021_24_01(_input: &str) -> Result<i64, Box<dyn std::error::Error>> {
     // Hard-coded answer from manual analysis
     // Largest valid MONAD number
     Ok(0)
 }
 
-pub fn solution_2021_24_02(_filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+pub fn solution_2021_24_01(_filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_24_01("")
+}
+
+pub fn solve_2021_24_02(_input: &str) -> Result<i64, Box<dyn std::error::Error>> {
     // Hard-coded answer from manual analysis
     // Smallest valid MONAD number
     Ok(0)
 }
 
+pub fn solution_2021_24_02(_filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_24_02("")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,11 +70,8 @@ fn move_cucumbers(data: &mut Vec<Vec<char>>) -> bool {
     return m1 || m2;
 }
 
-pub fn solution_2021_25_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
-    let mut input: Vec<Vec<char>> = fs::read_to_string(filepath)?
-        .lines()
-        .map(|l| l.chars().collect())
-        .collect();
+pub fn solve_2021_25_01(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let mut input: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
     let mut steps = 1;
     loop {
         let moved = move_cucumbers(&mut input);
@@ -87,11 +84,19 @@ pub fn solution_2021_25_01(filepath: String) -> Result<i64, Box<dyn std::error::
     Ok(steps)
 }
 
-pub fn solution_2021_25_02(_filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+pub fn solution_2021_25_01(filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_25_01(&fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2021_25_02(_input: &str) -> Result<i64, Box<dyn std::error::Error>> {
     // Day 25 part 2 is traditionally a freebie after completing all other days
     Ok(0)
 }
 
+pub fn solution_2021_25_02(_filepath: String) -> Result<i64, Box<dyn std::error::Error>> {
+    solve_2021_25_02("")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
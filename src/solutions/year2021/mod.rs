@@ -1,3 +1,5 @@
+use crate::registry::{solution, Entry};
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -23,3 +25,55 @@ pub mod day22;
 pub mod day23;
 pub mod day24;
 pub mod day25;
+
+pub static SOLUTIONS: &[Entry] = &[
+    solution!(2021, 1, 1, day01::solve_2021_01_01),
+    solution!(2021, 1, 2, day01::solve_2021_01_02),
+    solution!(2021, 2, 1, day02::solve_2021_02_01),
+    solution!(2021, 2, 2, day02::solve_2021_02_02),
+    solution!(2021, 3, 1, day03::solve_2021_03_01),
+    solution!(2021, 3, 2, day03::solve_2021_03_02),
+    solution!(2021, 4, 1, day04::solve_2021_04_01),
+    solution!(2021, 4, 2, day04::solve_2021_04_02),
+    solution!(2021, 5, 1, day05::solve_2021_05_01),
+    solution!(2021, 5, 2, day05::solve_2021_05_02),
+    solution!(2021, 6, 1, day06::solve_2021_06_01),
+    solution!(2021, 6, 2, day06::solve_2021_06_02),
+    solution!(2021, 7, 1, day07::solve_2021_07_01),
+    solution!(2021, 7, 2, day07::solve_2021_07_02),
+    solution!(2021, 8, 1, day08::solve_2021_08_01),
+    solution!(2021, 8, 2, day08::solve_2021_08_02),
+    solution!(2021, 9, 1, day09::solve_2021_09_01),
+    solution!(2021, 9, 2, day09::solve_2021_09_02),
+    solution!(2021, 10, 1, day10::solve_2021_10_01),
+    solution!(2021, 10, 2, day10::solve_2021_10_02),
+    solution!(2021, 11, 1, day11::solve_2021_11_01),
+    solution!(2021, 11, 2, day11::solve_2021_11_02),
+    solution!(2021, 12, 1, day12::solve_2021_12_01),
+    solution!(2021, 12, 2, day12::solve_2021_12_02),
+    solution!(2021, 13, 1, day13::solve_2021_13_01),
+    solution!(2021, 13, 2, day13::solve_2021_13_02),
+    solution!(2021, 14, 1, day14::solve_2021_14_01),
+    solution!(2021, 14, 2, day14::solve_2021_14_02),
+    solution!(2021, 15, 1, day15::solve_2021_15_01),
+    solution!(2021, 15, 2, day15::solve_2021_15_02),
+    solution!(2021, 16, 1, day16::solve_2021_16_01),
+    solution!(2021, 16, 2, day16::solve_2021_16_02),
+    solution!(2021, 17, 1, day17::solve_2021_17_01),
+    solution!(2021, 17, 2, day17::solve_2021_17_02),
+    solution!(2021, 18, 1, day18::solve_2021_18_01),
+    solution!(2021, 18, 2, day18::solve_2021_18_02),
+    solution!(2021, 19, 1, day19::solve_2021_19_01),
+    solution!(2021, 19, 2, day19::solve_2021_19_02),
+    solution!(2021, 20, 1, day20::solve_2021_20_01),
+    solution!(2021, 20, 2, day20::solve_2021_20_02),
+    solution!(2021, 21, 1, day21::solve_2021_21_01),
+    solution!(2021, 21, 2, day21::solve_2021_21_02),
+    solution!(2021, 22, 1, day22::solve_2021_22_01),
+    solution!(2021, 22, 2, day22::solve_2021_22_02),
+    solution!(2021, 23, 1, day23::solve_2021_23_01),
+    solution!(2021, 23, 2, day23::solve_2021_23_02),
+    solution!(2021, 24, 1, day24::solve_2021_24_01),
+    solution!(2021, 24, 2, day24::solve_2021_24_02),
+    solution!(2021, 25, 1, day25::solve_2021_25_01),
+];
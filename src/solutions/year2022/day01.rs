@@ -2,7 +2,7 @@
 
 use std::fs;
 
-fn get_calorie_counts(input: String) -> Vec<usize> {
+fn get_calorie_counts(input: &str) -> Vec<usize> {
     let mut calories: Vec<usize> = input
         .trim()
         .split("\n\n")
@@ -21,16 +21,24 @@ fn get_top_n(calories: &[usize], count: usize) -> usize {
     calories.iter().take(count).sum()
 }
 
-pub fn solution_2022_01_01(file_path: String) -> Option<usize> {
-    let input = get_calorie_counts(fs::read_to_string(file_path).unwrap());
+pub fn solve_2022_01_01(input: &str) -> Option<usize> {
+    let input = get_calorie_counts(input);
     Some(get_top_n(&input, 1))
 }
 
-pub fn solution_2022_01_02(file_path: String) -> Option<usize> {
-    let input = get_calorie_counts(fs::read_to_string(file_path).unwrap());
+pub fn solution_2022_01_01(file_path: String) -> Option<usize> {
+    solve_2022_01_01(&fs::read_to_string(file_path).unwrap())
+}
+
+pub fn solve_2022_01_02(input: &str) -> Option<usize> {
+    let input = get_calorie_counts(input);
     Some(get_top_n(&input, 3))
 }
 
+pub fn solution_2022_01_02(file_path: String) -> Option<usize> {
+    solve_2022_01_02(&fs::read_to_string(file_path).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,9 +30,8 @@ fn get_score_following_strategy(opponent: usize, strategy: &str) -> usize {
     }
 }
 
-pub fn solution_2022_02_01(file_path: String) -> Option<usize> {
-    let result = fs::read_to_string(file_path)
-        .expect("File not found")
+pub fn solve_2022_02_01(input: &str) -> Option<usize> {
+    let result = input
         .lines()
         .map(|r| {
             let mut entries = r.split(' ');
@@ -45,9 +44,12 @@ pub fn solution_2022_02_01(file_path: String) -> Option<usize> {
     Some(result)
 }
 
-pub fn solution_2022_02_02(file_path: String) -> Option<usize> {
-    let result = fs::read_to_string(file_path)
-        .expect("File not found")
+pub fn solution_2022_02_01(file_path: String) -> Option<usize> {
+    solve_2022_02_01(&fs::read_to_string(file_path).expect("File not found"))
+}
+
+pub fn solve_2022_02_02(input: &str) -> Option<usize> {
+    let result = input
         .lines()
         .map(|r| {
             let mut entries = r.split(' ');
@@ -60,6 +62,10 @@ pub fn solution_2022_02_02(file_path: String) -> Option<usize> {
     Some(result)
 }
 
+pub fn solution_2022_02_02(file_path: String) -> Option<usize> {
+    solve_2022_02_02(&fs::read_to_string(file_path).expect("File not found"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
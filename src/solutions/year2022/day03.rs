@@ -38,26 +38,23 @@ fn get_total_badge_priorities(input: Vec<String>) -> usize {
         .sum()
 }
 
+pub fn solve_2022_03_01(input: &str) -> Option<usize> {
+    Some(input.lines().map(|r| get_priority(get_common(r))).sum())
+}
+
 pub fn solution_2022_03_01(file_path: String) -> Option<usize> {
-    Some(
-        fs::read_to_string(file_path)
-            .expect("File couldn't be read.")
-            .lines()
-            .map(|r| get_priority(get_common(r)))
-            .sum(),
-    )
+    solve_2022_03_01(&fs::read_to_string(file_path).expect("File couldn't be read."))
 }
 
-pub fn solution_2022_03_02(file_path: String) -> Option<usize> {
-    let input: Vec<String> = fs::read_to_string(file_path)
-        .expect("File couldn't be read.")
-        .trim()
-        .lines()
-        .map(|l| l.to_string())
-        .collect();
+pub fn solve_2022_03_02(input: &str) -> Option<usize> {
+    let input: Vec<String> = input.trim().lines().map(|l| l.to_string()).collect();
     Some(get_total_badge_priorities(input))
 }
 
+pub fn solution_2022_03_02(file_path: String) -> Option<usize> {
+    solve_2022_03_02(&fs::read_to_string(file_path).expect("File couldn't be read."))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
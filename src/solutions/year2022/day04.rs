@@ -30,9 +30,8 @@ fn are_overlapping(pair: [[usize; 2]; 2]) -> bool {
     false
 }
 
-pub fn solution_2022_04_01(file_path: String) -> Option<usize> {
-    let result = fs::read_to_string(file_path)
-        .expect("Couldn't read file")
+pub fn solve_2022_04_01(input: &str) -> Option<usize> {
+    let result = input
         .trim()
         .lines()
         .map(|l| are_contained(parse_ranges(l)) as usize)
@@ -40,9 +39,12 @@ pub fn solution_2022_04_01(file_path: String) -> Option<usize> {
     Some(result)
 }
 
-pub fn solution_2022_04_02(file_path: String) -> Option<usize> {
-    let result = fs::read_to_string(file_path)
-        .expect("Couldn't read file")
+pub fn solution_2022_04_01(file_path: String) -> Option<usize> {
+    solve_2022_04_01(&fs::read_to_string(file_path).expect("Couldn't read file"))
+}
+
+pub fn solve_2022_04_02(input: &str) -> Option<usize> {
+    let result = input
         .trim()
         .lines()
         .map(|l| are_overlapping(parse_ranges(l)) as usize)
@@ -50,6 +52,10 @@ pub fn solution_2022_04_02(file_path: String) -> Option<usize> {
     Some(result)
 }
 
+pub fn solution_2022_04_02(file_path: String) -> Option<usize> {
+    solve_2022_04_02(&fs::read_to_string(file_path).expect("Couldn't read file"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,20 +63,26 @@ fn parse_input(inp: &str) -> (Vec<Vec<char>>, Vec<Instruction>) {
     (stacks, instructions)
 }
 
-pub fn solution_2022_05_01(file_path: String) -> Option<String> {
-    let input = fs::read_to_string(file_path).unwrap();
-    let (mut stacks, instructions) = parse_input(input.as_str());
+pub fn solve_2022_05_01(input: &str) -> Option<String> {
+    let (mut stacks, instructions) = parse_input(input);
     execute_instructions(&mut stacks, &instructions);
     Some(get_top(&stacks))
 }
 
-pub fn solution_2022_05_02(file_path: String) -> Option<String> {
-    let input = fs::read_to_string(file_path).unwrap();
-    let (mut stacks, instructions) = parse_input(input.as_str());
+pub fn solution_2022_05_01(file_path: String) -> Option<String> {
+    solve_2022_05_01(&fs::read_to_string(file_path).unwrap())
+}
+
+pub fn solve_2022_05_02(input: &str) -> Option<String> {
+    let (mut stacks, instructions) = parse_input(input);
     execute_instructions_by_new_crane(&mut stacks, &instructions);
     Some(get_top(&stacks))
 }
 
+pub fn solution_2022_05_02(file_path: String) -> Option<String> {
+    solve_2022_05_02(&fs::read_to_string(file_path).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
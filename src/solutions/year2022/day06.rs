@@ -11,12 +11,20 @@ fn detect_distinct(inp: &str, amount: usize) -> Option<usize> {
     result.map(|(idx, _)| idx + amount)
 }
 
+pub fn solve_2022_06_01(input: &str) -> Option<usize> {
+    detect_distinct(input.trim(), 4)
+}
+
 pub fn solution_2022_06_01(file_path: String) -> Option<usize> {
-    detect_distinct(fs::read_to_string(file_path).unwrap().trim(), 4)
+    solve_2022_06_01(&fs::read_to_string(file_path).unwrap())
+}
+
+pub fn solve_2022_06_02(input: &str) -> Option<usize> {
+    detect_distinct(input.trim(), 14)
 }
 
 pub fn solution_2022_06_02(file_path: String) -> Option<usize> {
-    detect_distinct(fs::read_to_string(file_path).unwrap().trim(), 14)
+    solve_2022_06_02(&fs::read_to_string(file_path).unwrap())
 }
 
 #[cfg(test)]
@@ -73,8 +73,8 @@ fn get_all_dir_sizes(dir_map: &HashMap<String, Directory>) -> Vec<usize> {
     dir_map.values().map(|dir| dir.size).collect()
 }
 
-pub fn solution_2022_07_01(file_path: String) -> Option<usize> {
-    let input = fs::read_to_string(file_path).unwrap().trim().to_string();
+pub fn solve_2022_07_01(input: &str) -> Option<usize> {
+    let input = input.trim().to_string();
     Some(
         get_all_dir_sizes(&run_commands(input))
             .iter()
@@ -83,8 +83,12 @@ pub fn solution_2022_07_01(file_path: String) -> Option<usize> {
     )
 }
 
-pub fn solution_2022_07_02(file_path: String) -> Option<usize> {
-    let input = fs::read_to_string(file_path).unwrap().trim().to_string();
+pub fn solution_2022_07_01(file_path: String) -> Option<usize> {
+    solve_2022_07_01(&fs::read_to_string(file_path).unwrap())
+}
+
+pub fn solve_2022_07_02(input: &str) -> Option<usize> {
+    let input = input.trim().to_string();
     let mut sizes = get_all_dir_sizes(&run_commands(input));
     sizes.sort();
     let (total_size, required_space, used) = (70000000, 30000000, sizes.last().unwrap());
@@ -93,6 +97,10 @@ pub fn solution_2022_07_02(file_path: String) -> Option<usize> {
     Some(*sizes.first().unwrap())
 }
 
+pub fn solution_2022_07_02(file_path: String) -> Option<usize> {
+    solve_2022_07_02(&fs::read_to_string(file_path).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
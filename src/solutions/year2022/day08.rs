@@ -85,10 +85,9 @@ fn largest_scenic_score(trees: &Faux2DArray<usize>) -> usize {
     largest
 }
 
-fn parse_input(file_path: String) -> Faux2DArray<usize> {
+fn parse_input(input: &str) -> Faux2DArray<usize> {
     let mut width = 0;
-    let mut trees: Faux2DArray<usize> = fs::read_to_string(file_path)
-        .unwrap()
+    let mut trees: Faux2DArray<usize> = input
         .trim()
         .lines()
         .flat_map(|l| {
@@ -101,16 +100,24 @@ fn parse_input(file_path: String) -> Faux2DArray<usize> {
     trees
 }
 
-pub fn solution_2022_08_01(file_path: String) -> Option<usize> {
-    let trees = parse_input(file_path);
+pub fn solve_2022_08_01(input: &str) -> Option<usize> {
+    let trees = parse_input(input);
     Some(get_visible(&trees))
 }
 
-pub fn solution_2022_08_02(file_path: String) -> Option<usize> {
-    let trees = parse_input(file_path);
+pub fn solution_2022_08_01(file_path: String) -> Option<usize> {
+    solve_2022_08_01(&fs::read_to_string(file_path).unwrap())
+}
+
+pub fn solve_2022_08_02(input: &str) -> Option<usize> {
+    let trees = parse_input(input);
     Some(largest_scenic_score(&trees))
 }
 
+pub fn solution_2022_08_02(file_path: String) -> Option<usize> {
+    solve_2022_08_02(&fs::read_to_string(file_path).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
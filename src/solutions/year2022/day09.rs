@@ -73,22 +73,30 @@ impl Rope {
     }
 }
 
-pub fn solution_2022_09_01(file_path: String) -> Option<usize> {
+pub fn solve_2022_09_01(input: &str) -> Option<usize> {
     let mut rope = Rope::at_start(1);
-    for instruction in fs::read_to_string(file_path).unwrap().trim().lines() {
+    for instruction in input.trim().lines() {
         rope.run_instruction(instruction);
     }
     Some(rope.movement_record.len())
 }
 
-pub fn solution_2022_09_02(file_path: String) -> Option<usize> {
+pub fn solution_2022_09_01(file_path: String) -> Option<usize> {
+    solve_2022_09_01(&fs::read_to_string(file_path).unwrap())
+}
+
+pub fn solve_2022_09_02(input: &str) -> Option<usize> {
     let mut rope = Rope::at_start(9);
-    for instruction in fs::read_to_string(file_path).unwrap().trim().lines() {
+    for instruction in input.trim().lines() {
         rope.run_instruction(instruction);
     }
     Some(rope.movement_record.len())
 }
 
+pub fn solution_2022_09_02(file_path: String) -> Option<usize> {
+    solve_2022_09_02(&fs::read_to_string(file_path).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
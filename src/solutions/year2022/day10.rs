@@ -54,26 +54,26 @@ impl ClockCircuit {
     }
 }
 
-pub fn solution_2022_10_01(file_path: String) -> Option<isize> {
+pub fn solve_2022_10_01(input: &str) -> Option<isize> {
     let mut circuit = ClockCircuit::new();
-    fs::read_to_string(file_path)
-        .unwrap()
-        .trim()
-        .lines()
-        .for_each(|ins| circuit.execute(ins));
+    input.trim().lines().for_each(|ins| circuit.execute(ins));
     Some(circuit.strength)
 }
 
-pub fn solution_2022_10_02(file_path: String) -> Option<String> {
+pub fn solution_2022_10_01(file_path: String) -> Option<isize> {
+    solve_2022_10_01(&fs::read_to_string(file_path).unwrap())
+}
+
+pub fn solve_2022_10_02(input: &str) -> Option<String> {
     let mut circuit = ClockCircuit::new();
-    fs::read_to_string(file_path)
-        .unwrap()
-        .trim()
-        .lines()
-        .for_each(|ins| circuit.execute(ins));
+    input.trim().lines().for_each(|ins| circuit.execute(ins));
     Some(circuit.get_image())
 }
 
+pub fn solution_2022_10_02(file_path: String) -> Option<String> {
+    solve_2022_10_02(&fs::read_to_string(file_path).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,16 +109,8 @@ impl Monkey {
     }
 }
 
-fn run_monkey_game(
-    file_path: String,
-    round: usize,
-    stress_divider: Option<usize>,
-) -> Option<usize> {
-    let mut monkeys: Vec<Monkey> = fs::read_to_string(file_path)
-        .unwrap()
-        .split("\n\nMonkey")
-        .map(Monkey::from_string)
-        .collect();
+fn run_monkey_game(input: &str, round: usize, stress_divider: Option<usize>) -> Option<usize> {
+    let mut monkeys: Vec<Monkey> = input.split("\n\nMonkey").map(Monkey::from_string).collect();
     let len = monkeys.len();
     let stress_manager = match stress_divider {
         Some(d) => StressManagerEnum::Divide(d),
@@ -139,12 +131,20 @@ fn run_monkey_game(
     Some(scores[len - 2] * scores[len - 1])
 }
 
+pub fn solve_2022_11_01(input: &str) -> Option<usize> {
+    run_monkey_game(input, 20, Some(3))
+}
+
 pub fn solution_2022_11_01(file_path: String) -> Option<usize> {
-    run_monkey_game(file_path, 20, Some(3))
+    solve_2022_11_01(&fs::read_to_string(file_path).unwrap())
+}
+
+pub fn solve_2022_11_02(input: &str) -> Option<usize> {
+    run_monkey_game(input, 10_000, None)
 }
 
 pub fn solution_2022_11_02(file_path: String) -> Option<usize> {
-    run_monkey_game(file_path, 10_000, None)
+    solve_2022_11_02(&fs::read_to_string(file_path).unwrap())
 }
 
 #[cfg(test)]
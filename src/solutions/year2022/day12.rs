@@ -37,32 +37,27 @@ fn char_to_num(c: char) -> u8 {
     }
 }
 
-fn parse_input(file_path: String) -> (Faux2DArray<u8>, usize, usize) {
+fn parse_input(input: &str) -> (Faux2DArray<u8>, usize, usize) {
     let mut steps = Faux2DArray::new(5);
     let mut goal = (0, 0);
     let mut start = (0, 0);
-    fs::read_to_string(file_path)
-        .unwrap()
-        .trim()
-        .lines()
-        .enumerate()
-        .for_each(|(y, l)| {
-            let row: Vec<u8> = l
-                .chars()
-                .enumerate()
-                .map(|(x, c)| {
-                    if c == 'E' {
-                        goal = (x, y);
-                    }
-                    if c == 'S' {
-                        start = (x, y);
-                    }
-                    char_to_num(c)
-                })
-                .collect();
-            steps.width = row.len();
-            steps.add_row(row).unwrap();
-        });
+    input.trim().lines().enumerate().for_each(|(y, l)| {
+        let row: Vec<u8> = l
+            .chars()
+            .enumerate()
+            .map(|(x, c)| {
+                if c == 'E' {
+                    goal = (x, y);
+                }
+                if c == 'S' {
+                    start = (x, y);
+                }
+                char_to_num(c)
+            })
+            .collect();
+        steps.width = row.len();
+        steps.add_row(row).unwrap();
+    });
 
     let r_start = steps.absolute_index(start.0, start.1);
     let r_goal = steps.absolute_index(goal.0, goal.1);
@@ -135,14 +130,18 @@ fn shortest_distance(
     distances.get(&end).copied()
 }
 
-pub fn solution_2022_12_01(file_path: String) -> Option<usize> {
-    let (steps, start, end) = parse_input(file_path);
+pub fn solve_2022_12_01(input: &str) -> Option<usize> {
+    let (steps, start, end) = parse_input(input);
     let positions = create_path_map(&steps);
     shortest_distance(&positions, steps.items.len(), start, end)
 }
 
-pub fn solution_2022_12_02(file_path: String) -> Option<usize> {
-    let (steps, _, end) = parse_input(file_path);
+pub fn solution_2022_12_01(file_path: String) -> Option<usize> {
+    solve_2022_12_01(&fs::read_to_string(file_path).unwrap())
+}
+
+pub fn solve_2022_12_02(input: &str) -> Option<usize> {
+    let (steps, _, end) = parse_input(input);
     let positions = create_path_map(&steps);
     let length = steps.items.len();
 
@@ -156,6 +155,10 @@ pub fn solution_2022_12_02(file_path: String) -> Option<usize> {
         .unwrap()
 }
 
+pub fn solution_2022_12_02(file_path: String) -> Option<usize> {
+    solve_2022_12_02(&fs::read_to_string(file_path).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,9 +98,8 @@ impl From<&Vec<String>> for Packet {
     }
 }
 
-pub fn solution_2022_13_01(file_path: String) -> Option<usize> {
-    let result = fs::read_to_string(file_path)
-        .expect("Unreadable file")
+pub fn solve_2022_13_01(input: &str) -> Option<usize> {
+    let result = input
         .trim()
         .split("\n\n")
         .enumerate()
@@ -120,14 +119,17 @@ pub fn solution_2022_13_01(file_path: String) -> Option<usize> {
     Some(result)
 }
 
-pub fn solution_2022_13_02(file_path: String) -> Option<usize> {
+pub fn solution_2022_13_01(file_path: String) -> Option<usize> {
+    solve_2022_13_01(&fs::read_to_string(file_path).expect("Unreadable file"))
+}
+
+pub fn solve_2022_13_02(input: &str) -> Option<usize> {
     let dividers = [
         Packet::List(vec![Packet::List(vec![Packet::Value(2)])]),
         Packet::List(vec![Packet::List(vec![Packet::Value(6)])]),
     ];
 
-    let mut packets: Vec<Packet> = fs::read_to_string(file_path)
-        .expect("Unreadable file")
+    let mut packets: Vec<Packet> = input
         .trim()
         .split("\n\n")
         .flat_map(|pair| pair.lines().map(|l| Packet::from_str(l).unwrap()))
@@ -146,6 +148,10 @@ pub fn solution_2022_13_02(file_path: String) -> Option<usize> {
     )
 }
 
+pub fn solution_2022_13_02(file_path: String) -> Option<usize> {
+    solve_2022_13_02(&fs::read_to_string(file_path).expect("Unreadable file"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
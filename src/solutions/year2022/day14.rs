@@ -62,13 +62,8 @@ fn find_destination(
     None
 }
 
-pub fn solution_2022_14_01(file_path: String) -> Option<usize> {
-    let mut paths: HashSet<(usize, usize)> = fs::read_to_string(file_path)
-        .unwrap()
-        .trim()
-        .lines()
-        .flat_map(parse_paths)
-        .collect();
+pub fn solve_2022_14_01(input: &str) -> Option<usize> {
+    let mut paths: HashSet<(usize, usize)> = input.trim().lines().flat_map(parse_paths).collect();
     let bottom = paths.iter().map(|x| x.1).max().unwrap();
 
     let mut step = 0;
@@ -83,13 +78,12 @@ pub fn solution_2022_14_01(file_path: String) -> Option<usize> {
     Some(step)
 }
 
-pub fn solution_2022_14_02(file_path: String) -> Option<usize> {
-    let mut paths: HashSet<(usize, usize)> = fs::read_to_string(file_path)
-        .unwrap()
-        .trim()
-        .lines()
-        .flat_map(parse_paths)
-        .collect();
+pub fn solution_2022_14_01(file_path: String) -> Option<usize> {
+    solve_2022_14_01(&fs::read_to_string(file_path).unwrap())
+}
+
+pub fn solve_2022_14_02(input: &str) -> Option<usize> {
+    let mut paths: HashSet<(usize, usize)> = input.trim().lines().flat_map(parse_paths).collect();
     let bottom = paths.iter().map(|x| x.1).max().unwrap() + 1;
 
     let mut step = 0;
@@ -105,6 +99,10 @@ pub fn solution_2022_14_02(file_path: String) -> Option<usize> {
     Some(step)
 }
 
+pub fn solution_2022_14_02(file_path: String) -> Option<usize> {
+    solve_2022_14_02(&fs::read_to_string(file_path).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -52,17 +52,16 @@ pub fn parse_coordinates(inp: &str) -> Coordinates {
     )
 }
 
-fn parse_input(file_path: String) -> Vec<Sensor> {
-    fs::read_to_string(file_path)
-        .unwrap()
+fn parse_input(input: &str) -> Vec<Sensor> {
+    input
         .trim()
         .lines()
         .map(|l| Sensor::from_str(l).unwrap())
         .collect()
 }
 
-pub fn solution_2022_15_01(file_path: String, y: isize) -> Option<usize> {
-    let sensors = parse_input(file_path);
+pub fn solve_2022_15_01(input: &str, y: isize) -> Option<usize> {
+    let sensors = parse_input(input);
     let x_bound = (
         sensors
             .iter()
@@ -83,8 +82,12 @@ pub fn solution_2022_15_01(file_path: String, y: isize) -> Option<usize> {
     )
 }
 
-pub fn solution_2022_15_02(file_path: String, bound: isize) -> Option<isize> {
-    let sensors = parse_input(file_path);
+pub fn solution_2022_15_01(file_path: String, y: isize) -> Option<usize> {
+    solve_2022_15_01(&fs::read_to_string(file_path).unwrap(), y)
+}
+
+pub fn solve_2022_15_02(input: &str, bound: isize) -> Option<isize> {
+    let sensors = parse_input(input);
     sensors.iter().find_map(|s| {
         ((s.coordinates.0 - s.radius - 1).max(0)..=s.coordinates.0.min(bound))
             .zip(s.coordinates.1..=(s.coordinates.1 + s.radius).min(bound))
@@ -97,6 +100,10 @@ pub fn solution_2022_15_02(file_path: String, bound: isize) -> Option<isize> {
     })
 }
 
+pub fn solution_2022_15_02(file_path: String, bound: isize) -> Option<isize> {
+    solve_2022_15_02(&fs::read_to_string(file_path).unwrap(), bound)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
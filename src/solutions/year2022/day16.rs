@@ -30,9 +30,8 @@ impl FromStr for Valve {
     }
 }
 
-fn parse_input(file_path: String) -> HashMap<String, Valve> {
-    fs::read_to_string(file_path)
-        .unwrap()
+fn parse_input(input: &str) -> HashMap<String, Valve> {
+    input
         .trim()
         .lines()
         .map(|l| {
@@ -157,13 +156,17 @@ fn get_flow_history_at(
     flows
 }
 
+pub fn solve_2022_16_01(input: &str) -> Option<usize> {
+    // let valves = parse_input(input);
+    get_flow_at(&parse_input(input), 30)
+}
+
 pub fn solution_2022_16_01(file_path: String) -> Option<usize> {
-    // let valves = parse_input(file_path);
-    get_flow_at(&parse_input(file_path), 30)
+    solve_2022_16_01(&fs::read_to_string(file_path).unwrap())
 }
 
-pub fn solution_2022_16_02(file_path: String) -> Option<usize> {
-    let valves = parse_input(file_path);
+pub fn solve_2022_16_02(input: &str) -> Option<usize> {
+    let valves = parse_input(input);
     let flows = get_flow_history_at(&valves, 26);
     let mut sanitized_flow: HashMap<usize, usize> = HashMap::new();
     for ((_, open, t), flow) in flows.iter() {
@@ -183,6 +186,10 @@ pub fn solution_2022_16_02(file_path: String) -> Option<usize> {
     Some(total_flow)
 }
 
+pub fn solution_2022_16_02(file_path: String) -> Option<usize> {
+    solve_2022_16_02(&fs::read_to_string(file_path).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,5 @@
+use crate::registry::{solution, Entry};
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -14,3 +16,38 @@ pub mod day13;
 pub mod day14;
 pub mod day15;
 pub mod day16;
+
+pub static SOLUTIONS: &[Entry] = &[
+    solution!(2022, 1, 1, day01::solve_2022_01_01),
+    solution!(2022, 1, 2, day01::solve_2022_01_02),
+    solution!(2022, 2, 1, day02::solve_2022_02_01),
+    solution!(2022, 2, 2, day02::solve_2022_02_02),
+    solution!(2022, 3, 1, day03::solve_2022_03_01),
+    solution!(2022, 3, 2, day03::solve_2022_03_02),
+    solution!(2022, 4, 1, day04::solve_2022_04_01),
+    solution!(2022, 4, 2, day04::solve_2022_04_02),
+    solution!(2022, 5, 1, day05::solve_2022_05_01),
+    solution!(2022, 5, 2, day05::solve_2022_05_02),
+    solution!(2022, 6, 1, day06::solve_2022_06_01),
+    solution!(2022, 6, 2, day06::solve_2022_06_02),
+    solution!(2022, 7, 1, day07::solve_2022_07_01),
+    solution!(2022, 7, 2, day07::solve_2022_07_02),
+    solution!(2022, 8, 1, day08::solve_2022_08_01),
+    solution!(2022, 8, 2, day08::solve_2022_08_02),
+    solution!(2022, 9, 1, day09::solve_2022_09_01),
+    solution!(2022, 9, 2, day09::solve_2022_09_02),
+    solution!(2022, 10, 1, day10::solve_2022_10_01),
+    solution!(2022, 10, 2, day10::solve_2022_10_02),
+    solution!(2022, 11, 1, day11::solve_2022_11_01),
+    solution!(2022, 11, 2, day11::solve_2022_11_02),
+    solution!(2022, 12, 1, day12::solve_2022_12_01),
+    solution!(2022, 12, 2, day12::solve_2022_12_02),
+    solution!(2022, 13, 1, day13::solve_2022_13_01),
+    solution!(2022, 13, 2, day13::solve_2022_13_02),
+    solution!(2022, 14, 1, day14::solve_2022_14_01),
+    solution!(2022, 14, 2, day14::solve_2022_14_02),
+    solution!(2022, 15, 1, day15::solve_2022_15_01, 2000000),
+    solution!(2022, 15, 2, day15::solve_2022_15_02, 4000000),
+    solution!(2022, 16, 1, day16::solve_2022_16_01),
+    solution!(2022, 16, 2, day16::solve_2022_16_02),
+];
@@ -48,31 +48,32 @@ fn get_calibration_value_extended(val: &str, p: &Regex, rev_p: &Regex) -> u32 {
     (get_converted_value(first) * 10) + get_converted_value(&last)
 }
 
+pub fn solve_2023_01_01(input: &str) -> Option<u32> {
+    Some(input.lines().map(get_calibration_value).sum())
+}
+
 pub fn solution_2023_01_01(file_path: String) -> Option<u32> {
-    Some(
-        fs::read_to_string(file_path)
-            .expect("Invalid File")
-            .lines()
-            .map(get_calibration_value)
-            .sum(),
-    )
+    solve_2023_01_01(&fs::read_to_string(file_path).expect("Invalid File"))
 }
 
-pub fn solution_2023_01_02(file_path: String) -> Option<u32> {
+pub fn solve_2023_01_02(input: &str) -> Option<u32> {
     let pattern = Regex::new(r"one|two|three|four|five|six|seven|eight|nine|\d")
         .expect("Unable to compile regex");
     let reverse_pattern = Regex::new(r"enin|thgie|neves|xis|evif|ruof|eerht|owt|eno|\d")
         .expect("Unable to compile regex");
 
     Some(
-        fs::read_to_string(file_path)
-            .expect("Invalid File")
+        input
             .lines()
             .map(|l| get_calibration_value_extended(l, &pattern, &reverse_pattern))
             .sum(),
     )
 }
 
+pub fn solution_2023_01_02(file_path: String) -> Option<u32> {
+    solve_2023_01_02(&fs::read_to_string(file_path).expect("Invalid File"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,9 +44,8 @@ impl Game {
     }
 }
 
-pub fn solution_2023_02_01(file_path: String) -> Option<usize> {
-    let result = fs::read_to_string(file_path)
-        .expect("Invalid input file.")
+pub fn solve_2023_02_01(input: &str) -> Option<usize> {
+    let result = input
         .lines()
         .enumerate()
         .filter_map(|(idx, l)| {
@@ -61,15 +60,22 @@ pub fn solution_2023_02_01(file_path: String) -> Option<usize> {
     Some(result)
 }
 
-pub fn solution_2023_02_02(file_path: String) -> Option<usize> {
-    let result = fs::read_to_string(file_path)
-        .expect("Invalid input file.")
+pub fn solution_2023_02_01(file_path: String) -> Option<usize> {
+    solve_2023_02_01(&fs::read_to_string(file_path).expect("Invalid input file."))
+}
+
+pub fn solve_2023_02_02(input: &str) -> Option<usize> {
+    let result = input
         .lines()
         .map(|l| l.parse::<Game>().unwrap().power())
         .sum();
     Some(result)
 }
 
+pub fn solution_2023_02_02(file_path: String) -> Option<usize> {
+    solve_2023_02_02(&fs::read_to_string(file_path).expect("Invalid input file."))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,4 @@
+use crate::solution::{parse_with, Solution};
 use std::fs;
 
 // Advent of Code 2023 - Day 03
@@ -17,13 +18,13 @@ struct Symbol {
 }
 
 #[derive(Debug)]
-struct Schema {
+pub struct Schema {
     parts: Vec<PartIndex>,
     symbols: Vec<Symbol>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct ParseSchemaError;
+pub struct ParseSchemaError;
 
 impl std::str::FromStr for Schema {
     type Err = ParseSchemaError;
@@ -78,7 +79,7 @@ impl std::str::FromStr for Schema {
 }
 
 impl Schema {
-    pub fn find_adjacent(&self, sym: &Symbol) -> Vec<&PartIndex> {
+    fn find_adjacent(&self, sym: &Symbol) -> Vec<&PartIndex> {
         let min_row_limit = if sym.y == 0 { 0 } else { sym.y - 1 };
         let min_col_limit = if sym.x == 0 { 0 } else { sym.x - 1 };
         self.parts
@@ -97,11 +98,11 @@ impl Schema {
             .collect()
     }
 
-    pub fn find_all_valid_parts(&self) -> impl Iterator<Item = &PartIndex> {
+    fn find_all_valid_parts(&self) -> impl Iterator<Item = &PartIndex> {
         self.symbols.iter().flat_map(|sym| self.find_adjacent(sym))
     }
 
-    pub fn get_gear_ratio(&self, sym: &Symbol) -> Option<usize> {
+    fn get_gear_ratio(&self, sym: &Symbol) -> Option<usize> {
         if sym.symbol != '*' {
             return None;
         }
@@ -113,26 +114,46 @@ impl Schema {
     }
 }
 
-pub fn solution_2023_03_01(file_path: String) -> Option<usize> {
-    let schema: Schema = fs::read_to_string(file_path)
-        .expect("Invalid File.")
-        .parse()
-        .unwrap();
-    let res = schema.find_all_valid_parts().map(|p| p.num).sum();
-    Some(res)
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Schema;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Schema> {
+        parse_with(input)
+    }
+
+    fn part1(schema: &Schema) -> anyhow::Result<usize> {
+        Ok(schema.find_all_valid_parts().map(|p| p.num).sum())
+    }
+
+    fn part2(schema: &Schema) -> anyhow::Result<usize> {
+        Ok(schema
+            .symbols
+            .iter()
+            .filter_map(|s| schema.get_gear_ratio(s))
+            .sum())
+    }
+}
+
+pub fn solve_2023_03_01(input: &str) -> anyhow::Result<usize> {
+    let parsed = Day03::parse(input)?;
+    Day03::part1(&parsed)
+}
+
+pub fn solution_2023_03_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_03_01(&fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2023_03_02(input: &str) -> anyhow::Result<usize> {
+    let parsed = Day03::parse(input)?;
+    Day03::part2(&parsed)
 }
 
-pub fn solution_2023_03_02(file_path: String) -> Option<usize> {
-    let schema: Schema = fs::read_to_string(file_path)
-        .expect("Invalid File.")
-        .parse()
-        .unwrap();
-    let res = schema
-        .symbols
-        .iter()
-        .filter_map(|s| schema.get_gear_ratio(s))
-        .sum();
-    Some(res)
+pub fn solution_2023_03_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_03_02(&fs::read_to_string(file_path)?)
 }
 
 #[cfg(test)]
@@ -34,25 +34,23 @@ fn total_won(cards: &Vec<usize>) -> usize {
     count_cache.iter().sum()
 }
 
+pub fn solve_2023_04_01(input: &str) -> Option<usize> {
+    Some(input.lines().map(|l| points(win_count(l))).sum())
+}
+
 pub fn solution_2023_04_01(file_path: String) -> Option<usize> {
-    Some(
-        fs::read_to_string(file_path)
-            .expect("Invalid File")
-            .lines()
-            .map(|l| points(win_count(l)))
-            .sum(),
-    )
+    solve_2023_04_01(&fs::read_to_string(file_path).expect("Invalid File"))
 }
 
-pub fn solution_2023_04_02(file_path: String) -> Option<usize> {
-    let cards: Vec<usize> = fs::read_to_string(file_path)
-        .expect("Invalid File")
-        .lines()
-        .map(win_count)
-        .collect();
+pub fn solve_2023_04_02(input: &str) -> Option<usize> {
+    let cards: Vec<usize> = input.lines().map(win_count).collect();
     Some(total_won(&cards))
 }
 
+pub fn solution_2023_04_02(file_path: String) -> Option<usize> {
+    solve_2023_04_02(&fs::read_to_string(file_path).expect("Invalid File"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
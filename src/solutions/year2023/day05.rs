@@ -1,3 +1,4 @@
+use crate::solution::{parse_with, Solution};
 use std::fs;
 
 // Advent of Code 2023 - Day 05
@@ -94,13 +95,13 @@ impl AlmanacRangeVec for Vec<AlmanacRange> {
     }
 }
 
-struct Almanac {
+pub struct Almanac {
     seeds: Vec<usize>,
     rules: Vec<Vec<AlmanacRange>>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct ParseAlmanacError;
+pub struct ParseAlmanacError;
 
 impl std::str::FromStr for Almanac {
     type Err = ParseAlmanacError;
@@ -167,43 +168,64 @@ impl Almanac {
     }
 }
 
-pub fn solution_2023_05_01(file_path: String) -> Option<usize> {
-    let almanac: Almanac = fs::read_to_string(file_path)
-        .expect("Invalid Input file.")
-        .parse()
-        .unwrap();
-
-    let res = almanac
-        .seeds
-        .iter()
-        .map(|seed| almanac.find_location(*seed))
-        .min();
-    res
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = Almanac;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Almanac> {
+        parse_with(input)
+    }
+
+    fn part1(almanac: &Almanac) -> anyhow::Result<usize> {
+        almanac
+            .seeds
+            .iter()
+            .map(|seed| almanac.find_location(*seed))
+            .min()
+            .ok_or_else(|| anyhow::anyhow!("No seeds"))
+    }
+
+    fn part2(almanac: &Almanac) -> anyhow::Result<usize> {
+        let seed_ranges = almanac.seeds_as_ranges();
+        let res = almanac
+            .rules
+            .iter()
+            .fold(seed_ranges, |ranges, section| {
+                let mut vals: Vec<AlmanacRange> = ranges
+                    .iter()
+                    .flat_map(|sr| section.generate_ranges(sr))
+                    .filter(|r| r.length > 0)
+                    .collect();
+                vals.sort_by_key(|r| r.start);
+                vals.merge()
+            })
+            .iter()
+            .min()
+            .ok_or_else(|| anyhow::anyhow!("No seed ranges"))?
+            .start;
+        Ok(res)
+    }
+}
+
+pub fn solve_2023_05_01(input: &str) -> anyhow::Result<usize> {
+    let almanac = Day05::parse(input)?;
+    Day05::part1(&almanac)
+}
+
+pub fn solution_2023_05_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_05_01(&fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2023_05_02(input: &str) -> anyhow::Result<usize> {
+    let almanac = Day05::parse(input)?;
+    Day05::part2(&almanac)
 }
 
-pub fn solution_2023_05_02(file_path: String) -> Option<usize> {
-    let almanac: Almanac = fs::read_to_string(file_path)
-        .expect("Invalid Input file.")
-        .parse()
-        .unwrap();
-    let seed_ranges = almanac.seeds_as_ranges();
-    let res = almanac
-        .rules
-        .iter()
-        .fold(seed_ranges, |ranges, section| {
-            let mut vals: Vec<AlmanacRange> = ranges
-                .iter()
-                .flat_map(|sr| section.generate_ranges(sr))
-                .filter(|r| r.length > 0)
-                .collect();
-            vals.sort_by(|a, b| a.start.cmp(&b.start));
-            vals.merge()
-        })
-        .iter()
-        .min()
-        .unwrap()
-        .start;
-    Some(res)
+pub fn solution_2023_05_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_05_02(&fs::read_to_string(file_path)?)
 }
 
 #[cfg(test)]
@@ -41,18 +41,20 @@ fn parse_combined(data: &str) -> (f64, f64) {
     (parts.next().unwrap(), parts.next().unwrap())
 }
 
+pub fn solve_2023_06_01(input: &str) -> Option<usize> {
+    Some(parse(input).iter().fold(1, |acc, s| acc * winning_count(s)))
+}
+
 pub fn solution_2023_06_01(file_path: String) -> Option<usize> {
-    Some(
-        parse(&fs::read_to_string(file_path).expect("Invalid Input."))
-            .iter()
-            .fold(1, |acc, s| acc * winning_count(s)),
-    )
+    solve_2023_06_01(&fs::read_to_string(file_path).expect("Invalid Input."))
+}
+
+pub fn solve_2023_06_02(input: &str) -> Option<usize> {
+    Some(winning_count(&parse_combined(input)))
 }
 
 pub fn solution_2023_06_02(file_path: String) -> Option<usize> {
-    Some(winning_count(&parse_combined(
-        &fs::read_to_string(file_path).expect("Invalid Input."),
-    )))
+    solve_2023_06_02(&fs::read_to_string(file_path).expect("Invalid Input."))
 }
 
 #[cfg(test)]
@@ -60,12 +60,8 @@ impl Hand {
     }
 }
 
-pub fn get_total(file_path: String, wild: bool) -> Option<usize> {
-    let mut hands: Vec<Hand> = fs::read_to_string(file_path)
-        .expect("Invalid Input File.")
-        .par_lines()
-        .map(|l| Hand::from_str(l, wild))
-        .collect();
+pub fn get_total(input: &str, wild: bool) -> Option<usize> {
+    let mut hands: Vec<Hand> = input.par_lines().map(|l| Hand::from_str(l, wild)).collect();
     hands.sort();
     Some(
         hands
@@ -75,12 +71,20 @@ pub fn get_total(file_path: String, wild: bool) -> Option<usize> {
     )
 }
 
+pub fn solve_2023_07_01(input: &str) -> Option<usize> {
+    get_total(input, false)
+}
+
 pub fn solution_2023_07_01(file_path: String) -> Option<usize> {
-    get_total(file_path, false)
+    solve_2023_07_01(&fs::read_to_string(file_path).expect("Invalid Input File."))
+}
+
+pub fn solve_2023_07_02(input: &str) -> Option<usize> {
+    get_total(input, true)
 }
 
 pub fn solution_2023_07_02(file_path: String) -> Option<usize> {
-    get_total(file_path, true)
+    solve_2023_07_02(&fs::read_to_string(file_path).expect("Invalid Input File."))
 }
 
 #[cfg(test)]
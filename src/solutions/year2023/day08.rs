@@ -1,3 +1,4 @@
+use crate::solution::{parse_with, Solution};
 use num::integer::lcm;
 use rayon::prelude::*;
 use std::{collections::HashMap, fs};
@@ -47,13 +48,13 @@ impl std::str::FromStr for Node {
 }
 
 #[derive(Debug)]
-struct Map {
+pub struct Map {
     instructions: Vec<Direction>,
     nodes: HashMap<String, Node>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
-struct ParseMapError;
+pub struct ParseMapError;
 
 impl std::str::FromStr for Map {
     type Err = ParseMapError;
@@ -107,20 +108,42 @@ impl Map {
     }
 }
 
-pub fn solution_2023_08_01(file_path: String) -> Option<usize> {
-    let map: Map = fs::read_to_string(file_path)
-        .expect("Invalid input.")
-        .parse()
-        .unwrap();
-    Some(map.traverse())
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Map> {
+        parse_with(input)
+    }
+
+    fn part1(map: &Map) -> anyhow::Result<usize> {
+        Ok(map.traverse())
+    }
+
+    fn part2(map: &Map) -> anyhow::Result<usize> {
+        Ok(map.traverse_like_a_ghost())
+    }
+}
+
+pub fn solve_2023_08_01(input: &str) -> anyhow::Result<usize> {
+    let parsed = Day08::parse(input)?;
+    Day08::part1(&parsed)
+}
+
+pub fn solution_2023_08_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_08_01(&fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2023_08_02(input: &str) -> anyhow::Result<usize> {
+    let parsed = Day08::parse(input)?;
+    Day08::part2(&parsed)
 }
 
-pub fn solution_2023_08_02(file_path: String) -> Option<usize> {
-    let map: Map = fs::read_to_string(file_path)
-        .expect("Invalid input.")
-        .parse()
-        .unwrap();
-    Some(map.traverse_like_a_ghost())
+pub fn solution_2023_08_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_08_02(&fs::read_to_string(file_path)?)
 }
 
 #[cfg(test)]
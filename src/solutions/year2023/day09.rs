@@ -1,5 +1,6 @@
 // Advent of Code 2023 - Day 09
 
+use crate::solution::Solution;
 use std::fs;
 
 fn parse(input: &str) -> Vec<Vec<isize>> {
@@ -37,14 +38,42 @@ fn report(data: &[Vec<isize>], accumulator: fn(isize, &Vec<isize>) -> isize) ->
         .sum()
 }
 
-pub fn solution_2023_09_01(file_path: String) -> Option<isize> {
-    let val = parse(&fs::read_to_string(file_path).expect("Invalid Input File."));
-    Some(report(&val, |acc, v| v.last().unwrap() + acc))
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = Vec<Vec<isize>>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Vec<isize>>> {
+        Ok(parse(input))
+    }
+
+    fn part1(val: &Vec<Vec<isize>>) -> anyhow::Result<isize> {
+        Ok(report(val, |acc, v| v.last().unwrap() + acc))
+    }
+
+    fn part2(val: &Vec<Vec<isize>>) -> anyhow::Result<isize> {
+        Ok(report(val, |acc, v| v.first().unwrap() - acc))
+    }
+}
+
+pub fn solve_2023_09_01(input: &str) -> anyhow::Result<isize> {
+    let parsed = Day09::parse(input)?;
+    Day09::part1(&parsed)
+}
+
+pub fn solution_2023_09_01(file_path: String) -> anyhow::Result<isize> {
+    solve_2023_09_01(&fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2023_09_02(input: &str) -> anyhow::Result<isize> {
+    let parsed = Day09::parse(input)?;
+    Day09::part2(&parsed)
 }
 
-pub fn solution_2023_09_02(file_path: String) -> Option<isize> {
-    let val = parse(&fs::read_to_string(file_path).expect("Invalid Input File."));
-    Some(report(&val, |acc, v| v.first().unwrap() - acc))
+pub fn solution_2023_09_02(file_path: String) -> anyhow::Result<isize> {
+    solve_2023_09_02(&fs::read_to_string(file_path)?)
 }
 
 #[cfg(test)]
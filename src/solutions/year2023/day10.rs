@@ -1,3 +1,4 @@
+use crate::solution::{parse_with, Solution};
 use std::{
     collections::{HashMap, HashSet, VecDeque},
     fs,
@@ -19,7 +20,7 @@ impl Terrain {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct GroundMap {
+pub struct GroundMap {
     start: Terrain,
     terrains: HashMap<String, Terrain>,
     width: usize,
@@ -27,7 +28,7 @@ struct GroundMap {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct ParseGroundMapError;
+pub struct ParseGroundMapError;
 
 impl std::str::FromStr for GroundMap {
     type Err = ParseGroundMapError;
@@ -308,23 +309,45 @@ impl GroundMap {
     }
 }
 
-pub fn solution_2023_10_01(file_path: String) -> Option<usize> {
-    let ground_map = fs::read_to_string(file_path)
-        .expect("Invalid File")
-        .parse::<GroundMap>()
-        .unwrap();
-    let pipes = ground_map.find_largest_loop();
-    let val = pipes.len();
-    Some((val / 2) + (val % 2))
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = GroundMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<GroundMap> {
+        parse_with(input)
+    }
+
+    fn part1(ground_map: &GroundMap) -> anyhow::Result<usize> {
+        let pipes = ground_map.find_largest_loop();
+        let val = pipes.len();
+        Ok((val / 2) + (val % 2))
+    }
+
+    fn part2(ground_map: &GroundMap) -> anyhow::Result<usize> {
+        let pipes = ground_map.find_largest_loop();
+        Ok(ground_map.count_enclosed(&pipes))
+    }
+}
+
+pub fn solve_2023_10_01(input: &str) -> anyhow::Result<usize> {
+    let parsed = Day10::parse(input)?;
+    Day10::part1(&parsed)
+}
+
+pub fn solution_2023_10_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_10_01(&fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2023_10_02(input: &str) -> anyhow::Result<usize> {
+    let parsed = Day10::parse(input)?;
+    Day10::part2(&parsed)
 }
 
-pub fn solution_2023_10_02(file_path: String) -> Option<usize> {
-    let ground_map = fs::read_to_string(file_path)
-        .expect("Invalid File")
-        .parse::<GroundMap>()
-        .unwrap();
-    let pipes = ground_map.find_largest_loop();
-    Some(ground_map.count_enclosed(&pipes))
+pub fn solution_2023_10_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_10_02(&fs::read_to_string(file_path)?)
 }
 
 #[cfg(test)]
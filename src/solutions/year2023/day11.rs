@@ -80,14 +80,18 @@ impl Observation {
     }
 }
 
-pub fn solution_2023_11(file_path: String, multiplier: usize) -> Option<usize> {
-    let data: Observation = fs::read_to_string(file_path)
-        .expect("Invalid File")
-        .parse()
-        .unwrap();
+pub fn solve_2023_11(input: &str, multiplier: usize) -> Option<usize> {
+    let data: Observation = input.parse().unwrap();
     Some(data.get_all_galaxy_distances(multiplier))
 }
 
+pub fn solution_2023_11(file_path: String, multiplier: usize) -> Option<usize> {
+    solve_2023_11(
+        &fs::read_to_string(file_path).expect("Invalid File"),
+        multiplier,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
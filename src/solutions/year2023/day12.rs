@@ -70,10 +70,9 @@ fn find_combinations(inp: &str, report: &[usize], cache: &mut HashMap<String, us
     result
 }
 
-pub fn solution_2023_12_01(file_path: String) -> Option<usize> {
+pub fn solve_2023_12_01(input: &str) -> Option<usize> {
     let mut cache = HashMap::new();
-    let total = fs::read_to_string(file_path)
-        .expect("Invalid File")
+    let total = input
         .lines()
         .map(|x| {
             let entry = x.parse::<DamageReport>().unwrap();
@@ -84,10 +83,13 @@ pub fn solution_2023_12_01(file_path: String) -> Option<usize> {
     Some(total)
 }
 
-pub fn solution_2023_12_02(file_path: String) -> Option<usize> {
+pub fn solution_2023_12_01(file_path: String) -> Option<usize> {
+    solve_2023_12_01(&fs::read_to_string(file_path).expect("Invalid File"))
+}
+
+pub fn solve_2023_12_02(input: &str) -> Option<usize> {
     let mut cache = HashMap::new();
-    let total = fs::read_to_string(file_path)
-        .expect("Invalid File")
+    let total = input
         .lines()
         .map(|x| {
             let entry = x.parse::<DamageReport>().unwrap();
@@ -99,6 +101,10 @@ pub fn solution_2023_12_02(file_path: String) -> Option<usize> {
     Some(total)
 }
 
+pub fn solution_2023_12_02(file_path: String) -> Option<usize> {
+    solve_2023_12_02(&fs::read_to_string(file_path).expect("Invalid File"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,9 +130,8 @@ impl Mirror {
     }
 }
 
-pub fn solution_2023_13_01(file_path: String) -> Option<usize> {
-    let result = fs::read_to_string(file_path)
-        .expect("Invalid Input file.")
+pub fn solve_2023_13_01(input: &str) -> Option<usize> {
+    let result = input
         .split("\n\n")
         .par_bridge()
         .map(|l| l.parse::<Mirror>().unwrap().score(0))
@@ -140,9 +139,12 @@ pub fn solution_2023_13_01(file_path: String) -> Option<usize> {
     Some(result)
 }
 
-pub fn solution_2023_13_02(file_path: String) -> Option<usize> {
-    let result = fs::read_to_string(file_path)
-        .expect("Invalid Input file.")
+pub fn solution_2023_13_01(file_path: String) -> Option<usize> {
+    solve_2023_13_01(&fs::read_to_string(file_path).expect("Invalid Input file."))
+}
+
+pub fn solve_2023_13_02(input: &str) -> Option<usize> {
+    let result = input
         .split("\n\n")
         .par_bridge()
         .map(|l| l.parse::<Mirror>().unwrap().score(1))
@@ -150,6 +152,10 @@ pub fn solution_2023_13_02(file_path: String) -> Option<usize> {
     Some(result)
 }
 
+pub fn solution_2023_13_02(file_path: String) -> Option<usize> {
+    solve_2023_13_02(&fs::read_to_string(file_path).expect("Invalid Input file."))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
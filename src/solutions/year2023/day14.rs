@@ -1,5 +1,6 @@
 // Advent of Code 2023 - Day 14
 
+use crate::solution::{parse_with, Solution};
 use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::fs;
@@ -8,10 +9,10 @@ use std::hash::{Hash, Hasher};
 use derive_deref::{Deref, DerefMut};
 
 #[derive(Debug, Clone, Hash, Deref, DerefMut)]
-struct Platform(Vec<Vec<char>>);
+pub struct Platform(Vec<Vec<char>>);
 
 #[derive(Debug, PartialEq, Eq)]
-struct ParsePlatformError;
+pub struct ParsePlatformError;
 
 impl std::str::FromStr for Platform {
     type Err = ParsePlatformError;
@@ -156,37 +157,61 @@ impl Platform {
     }
 }
 
-pub fn solution_2023_14_01(file_path: String) -> Option<usize> {
-    let mut platform = fs::read_to_string(file_path)
-        .expect("Invalid input file.")
-        .parse::<Platform>()
-        .unwrap();
-    platform.tilt_north();
-    Some(platform.calculate_load())
-}
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Platform;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Platform> {
+        parse_with(input)
+    }
 
-pub fn solution_2023_14_02(file_path: String) -> Option<usize> {
-    let mut platform = fs::read_to_string(file_path)
-        .expect("Invalid input file.")
-        .parse::<Platform>()
-        .unwrap();
-    let mut seen: HashMap<u64, usize> = HashMap::new();
-    for i in 0..1000000000 {
-        let hash = platform.get_hash();
-        if let std::collections::hash_map::Entry::Vacant(e) = seen.entry(hash) {
-            platform.tilt_cycle();
-            e.insert(i);
-        } else {
-            let cycle = i - seen.get(&hash).unwrap();
-            let remaining = 1000000000 - i;
-            let remaining = remaining % cycle;
-            for _ in 0..remaining {
+    fn part1(platform: &Platform) -> anyhow::Result<usize> {
+        let mut platform = platform.clone();
+        platform.tilt_north();
+        Ok(platform.calculate_load())
+    }
+
+    fn part2(platform: &Platform) -> anyhow::Result<usize> {
+        let mut platform = platform.clone();
+        let mut seen: HashMap<u64, usize> = HashMap::new();
+        for i in 0..1000000000 {
+            let hash = platform.get_hash();
+            if let std::collections::hash_map::Entry::Vacant(e) = seen.entry(hash) {
                 platform.tilt_cycle();
+                e.insert(i);
+            } else {
+                let cycle = i - seen.get(&hash).unwrap();
+                let remaining = 1000000000 - i;
+                let remaining = remaining % cycle;
+                for _ in 0..remaining {
+                    platform.tilt_cycle();
+                }
+                break;
             }
-            break;
         }
+        Ok(platform.calculate_load())
     }
-    Some(platform.calculate_load())
+}
+
+pub fn solve_2023_14_01(input: &str) -> anyhow::Result<usize> {
+    let parsed = Day14::parse(input)?;
+    Day14::part1(&parsed)
+}
+
+pub fn solution_2023_14_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_14_01(&fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2023_14_02(input: &str) -> anyhow::Result<usize> {
+    let parsed = Day14::parse(input)?;
+    Day14::part2(&parsed)
+}
+
+pub fn solution_2023_14_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_14_02(&fs::read_to_string(file_path)?)
 }
 
 #[cfg(test)]
@@ -87,24 +87,28 @@ impl std::str::FromStr for BoxArray {
     }
 }
 
-pub fn solution_2023_15_01(file_path: String) -> Option<usize> {
-    let res = fs::read_to_string(file_path)
-        .expect("Invalid Input File.")
+pub fn solve_2023_15_01(input: &str) -> Option<usize> {
+    let res = input
         .lines()
         .flat_map(|l| l.split(',').map(get_hash))
         .sum::<usize>();
     Some(res)
 }
 
-pub fn solution_2023_15_02(file_path: String) -> Option<usize> {
-    let boxes: BoxArray = fs::read_to_string(file_path)
-        .expect("Invalid Input File.")
-        .parse()
-        .unwrap();
+pub fn solution_2023_15_01(file_path: String) -> Option<usize> {
+    solve_2023_15_01(&fs::read_to_string(file_path).expect("Invalid Input File."))
+}
+
+pub fn solve_2023_15_02(input: &str) -> Option<usize> {
+    let boxes: BoxArray = input.parse().unwrap();
 
     Some(boxes.calculate_focusing_power())
 }
 
+pub fn solution_2023_15_02(file_path: String) -> Option<usize> {
+    solve_2023_15_02(&fs::read_to_string(file_path).expect("Invalid Input File."))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
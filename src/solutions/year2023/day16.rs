@@ -141,16 +141,20 @@ impl MirrorRoom {
     }
 }
 
-pub fn solution_2023_16_01(file_path: String) -> Option<usize> {
-    let mirror_room: MirrorRoom = fs::read_to_string(file_path).unwrap().parse().unwrap();
+pub fn solve_2023_16_01(input: &str) -> Option<usize> {
+    let mirror_room: MirrorRoom = input.parse().unwrap();
     Some(mirror_room.find_photons(&Photon {
         position: (-1, 0),
         direction: MovementDirection::Rightward,
     }))
 }
 
-pub fn solution_2023_16_02(file_path: String) -> Option<usize> {
-    let mirror_room: MirrorRoom = fs::read_to_string(file_path).unwrap().parse().unwrap();
+pub fn solution_2023_16_01(file_path: String) -> Option<usize> {
+    solve_2023_16_01(&fs::read_to_string(file_path).unwrap())
+}
+
+pub fn solve_2023_16_02(input: &str) -> Option<usize> {
+    let mirror_room: MirrorRoom = input.parse().unwrap();
 
     let (sender, receiver) = channel();
     (0..mirror_room.width)
@@ -192,6 +196,10 @@ pub fn solution_2023_16_02(file_path: String) -> Option<usize> {
     )
 }
 
+pub fn solution_2023_16_02(file_path: String) -> Option<usize> {
+    solve_2023_16_02(&fs::read_to_string(file_path).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,16 +1,17 @@
 // Advent of Code 2023 - Day 17
 
+use crate::solution::{parse_with, Solution};
 use std::collections::{BinaryHeap, HashMap};
 
 #[derive(Debug, Clone, Hash)]
-struct Graph {
+pub struct Graph {
     nodes: Vec<Vec<usize>>,
     width: usize,
     height: usize,
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct ParseGraphError;
+pub struct ParseGraphError;
 
 impl std::str::FromStr for Graph {
     type Err = ParseGraphError;
@@ -139,14 +140,42 @@ impl Graph {
     }
 }
 
-pub fn solution_2023_17_01(file_path: String) -> Option<usize> {
-    let graph: Graph = std::fs::read_to_string(file_path).unwrap().parse().unwrap();
-    Some(graph.find_shortest(1, 3))
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed = Graph;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Graph> {
+        parse_with(input)
+    }
+
+    fn part1(graph: &Graph) -> anyhow::Result<usize> {
+        Ok(graph.find_shortest(1, 3))
+    }
+
+    fn part2(graph: &Graph) -> anyhow::Result<usize> {
+        Ok(graph.find_shortest(4, 10))
+    }
+}
+
+pub fn solve_2023_17_01(input: &str) -> anyhow::Result<usize> {
+    let graph = Day17::parse(input)?;
+    Day17::part1(&graph)
+}
+
+pub fn solution_2023_17_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_17_01(&std::fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2023_17_02(input: &str) -> anyhow::Result<usize> {
+    let graph = Day17::parse(input)?;
+    Day17::part2(&graph)
 }
 
-pub fn solution_2023_17_02(file_path: String) -> Option<usize> {
-    let graph: Graph = std::fs::read_to_string(file_path).unwrap().parse().unwrap();
-    Some(graph.find_shortest(4, 10))
+pub fn solution_2023_17_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_17_02(&std::fs::read_to_string(file_path)?)
 }
 
 #[cfg(test)]
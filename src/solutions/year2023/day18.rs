@@ -1,8 +1,9 @@
+use crate::solution::{parse_with, Solution};
 use std::fs;
 
 // Advent of Code 2023 - Day 18
 
-struct Instruction {
+pub struct Instruction {
     dir: u8,
     len: usize,
     c: usize,
@@ -60,22 +61,43 @@ fn shoelace_area(
     }
     perimeter.wrapping_add_signed(sum) / 2 + 1
 }
-pub fn solution_2023_18_01(file_path: String) -> Option<usize> {
-    let plan: Vec<Instruction> = fs::read_to_string(file_path)
-        .expect("Invalid Input File.")
-        .lines()
-        .map(|l| l.parse::<Instruction>().unwrap())
-        .collect();
-    Some(shoelace_area(&plan, |i| (i.dir, i.len)))
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Instruction>> {
+        input.lines().map(parse_with).collect()
+    }
+
+    fn part1(plan: &Vec<Instruction>) -> anyhow::Result<usize> {
+        Ok(shoelace_area(plan, |i| (i.dir, i.len)))
+    }
+
+    fn part2(plan: &Vec<Instruction>) -> anyhow::Result<usize> {
+        Ok(shoelace_area(plan, |i| ((i.c & 3) as u8, (i.c >> 4))))
+    }
+}
+
+pub fn solve_2023_18_01(input: &str) -> anyhow::Result<usize> {
+    let parsed = Day18::parse(input)?;
+    Day18::part1(&parsed)
+}
+
+pub fn solution_2023_18_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_18_01(&fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2023_18_02(input: &str) -> anyhow::Result<usize> {
+    let parsed = Day18::parse(input)?;
+    Day18::part2(&parsed)
 }
 
-pub fn solution_2023_18_02(file_path: String) -> Option<usize> {
-    let plan: Vec<Instruction> = fs::read_to_string(file_path)
-        .expect("Invalid Input File.")
-        .lines()
-        .map(|l| l.parse::<Instruction>().unwrap())
-        .collect();
-    Some(shoelace_area(&plan, |i| ((i.c & 3) as u8, (i.c >> 4))))
+pub fn solution_2023_18_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_18_02(&fs::read_to_string(file_path)?)
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 // Advent of Code 2023 - Day 19
 
+use crate::solution::{parse_with, Solution};
 use std::{collections::HashMap, fs};
 
 use derive_deref::{Deref, DerefMut};
@@ -188,13 +189,13 @@ impl std::str::FromStr for Workflow {
 }
 
 #[derive(Debug, Clone)]
-struct WorkflowManager {
+pub struct WorkflowManager {
     workflows: HashMap<String, Workflow>,
     parts: Vec<Part>,
 }
 
 #[derive(Debug, Eq, PartialEq)]
-struct ParseWorkflowManagerError;
+pub struct ParseWorkflowManagerError;
 
 impl std::str::FromStr for WorkflowManager {
     type Err = ParseWorkflowManagerError;
@@ -289,20 +290,42 @@ impl WorkflowManager {
     }
 }
 
-pub fn solution_2023_19_01(file_path: String) -> Option<usize> {
-    let manager = fs::read_to_string(file_path)
-        .expect("Invalid Input File.")
-        .parse::<WorkflowManager>()
-        .unwrap();
-    Some(manager.get_total_accepted_value())
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed = WorkflowManager;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<WorkflowManager> {
+        parse_with(input)
+    }
+
+    fn part1(manager: &WorkflowManager) -> anyhow::Result<usize> {
+        Ok(manager.get_total_accepted_value())
+    }
+
+    fn part2(manager: &WorkflowManager) -> anyhow::Result<usize> {
+        Ok(manager.find_combinations())
+    }
+}
+
+pub fn solve_2023_19_01(input: &str) -> anyhow::Result<usize> {
+    let parsed = Day19::parse(input)?;
+    Day19::part1(&parsed)
+}
+
+pub fn solution_2023_19_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_19_01(&fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2023_19_02(input: &str) -> anyhow::Result<usize> {
+    let parsed = Day19::parse(input)?;
+    Day19::part2(&parsed)
 }
 
-pub fn solution_2023_19_02(file_path: String) -> Option<usize> {
-    let manager = fs::read_to_string(file_path)
-        .expect("Invalid Input File.")
-        .parse::<WorkflowManager>()
-        .unwrap();
-    Some(manager.find_combinations())
+pub fn solution_2023_19_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_19_02(&fs::read_to_string(file_path)?)
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 // Advent of Code 2023 - Day 20
 
+use crate::solution::{parse_with, Solution};
 use std::{
     collections::{HashMap, VecDeque},
     fs,
@@ -22,7 +23,7 @@ enum ModuleType {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct Module {
+pub struct Module {
     name: String,
     module_type: ModuleType,
     destinations: Vec<String>,
@@ -79,7 +80,7 @@ impl Module {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct ParseModuleError;
+pub struct ParseModuleError;
 
 impl std::str::FromStr for Module {
     type Err = ParseModuleError;
@@ -102,11 +103,11 @@ impl std::str::FromStr for Module {
     }
 }
 
-#[derive(Deref, DerefMut)]
-struct Circuit(HashMap<String, Module>);
+#[derive(Clone, Deref, DerefMut)]
+pub struct Circuit(HashMap<String, Module>);
 
 #[derive(Debug, PartialEq, Eq)]
-struct ParseCircuitError;
+pub struct ParseCircuitError;
 
 impl std::str::FromStr for Circuit {
     type Err = ParseCircuitError;
@@ -224,23 +225,44 @@ impl Circuit {
     }
 }
 
-pub fn solution_2023_20_01(file_path: String) -> Option<usize> {
-    let mut circuit = fs::read_to_string(file_path)
-        .expect("Invalid input file.")
-        .parse::<Circuit>()
-        .unwrap();
+pub struct Day20;
 
-    Some(circuit.total_pulse(1000))
-    // Some((0..1000).fold(0, |acc, _| acc + circuit.run()))
+impl Solution for Day20 {
+    type Parsed = Circuit;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Circuit> {
+        parse_with(input)
+    }
+
+    fn part1(circuit: &Circuit) -> anyhow::Result<usize> {
+        let mut circuit = circuit.clone();
+        Ok(circuit.total_pulse(1000))
+    }
+
+    fn part2(circuit: &Circuit) -> anyhow::Result<usize> {
+        let mut circuit = circuit.clone();
+        Ok(circuit.run_until_on())
+    }
 }
 
-pub fn solution_2023_20_02(file_path: String) -> Option<usize> {
-    let mut circuit = fs::read_to_string(file_path)
-        .expect("Invalid input file.")
-        .parse::<Circuit>()
-        .unwrap();
+pub fn solve_2023_20_01(input: &str) -> anyhow::Result<usize> {
+    let parsed = Day20::parse(input)?;
+    Day20::part1(&parsed)
+}
+
+pub fn solution_2023_20_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_20_01(&fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2023_20_02(input: &str) -> anyhow::Result<usize> {
+    let parsed = Day20::parse(input)?;
+    Day20::part2(&parsed)
+}
 
-    Some(circuit.run_until_on())
+pub fn solution_2023_20_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_20_02(&fs::read_to_string(file_path)?)
 }
 
 #[cfg(test)]
@@ -108,22 +108,28 @@ impl GardenMap {
     }
 }
 
-pub fn solution_2023_21_01(file_path: String, moves: usize) -> Option<usize> {
-    let map = std::fs::read_to_string(file_path)
-        .unwrap()
+pub fn solve_2023_21_01(input: &str, moves: usize) -> Option<usize> {
+    let map = input
         .parse::<GardenMap>()
         .unwrap();
     Some(map.possible_move_after_steps(moves).len())
 }
 
-pub fn solution_2023_21_02(file_path: String, moves: usize) -> Option<usize> {
-    let map = std::fs::read_to_string(file_path)
-        .unwrap()
+pub fn solution_2023_21_01(file_path: String, moves: usize) -> Option<usize> {
+    solve_2023_21_01(&std::fs::read_to_string(file_path).unwrap(), moves)
+}
+
+pub fn solve_2023_21_02(input: &str, moves: usize) -> Option<usize> {
+    let map = input
         .parse::<GardenMap>()
         .unwrap();
     Some(map.possible_move_after_steps_in_infinite_wrap(moves))
 }
 
+pub fn solution_2023_21_02(file_path: String, moves: usize) -> Option<usize> {
+    solve_2023_21_02(&std::fs::read_to_string(file_path).unwrap(), moves)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,20 +185,28 @@ impl Wall {
     }
 }
 
-pub fn solution_2023_22_01(file_path: String) -> anyhow::Result<usize> {
-    let mut wall = Wall::from_string(&std::fs::read_to_string(file_path)?)?;
+pub fn solve_2023_22_01(input: &str) -> anyhow::Result<usize> {
+    let mut wall = Wall::from_string(input)?;
     wall.settle();
     wall.build_support_graph();
     Ok(wall.count_removable_bricks())
 }
 
-pub fn solution_2023_22_02(file_path: String) -> anyhow::Result<usize> {
-    let mut wall = Wall::from_string(&std::fs::read_to_string(file_path)?)?;
+pub fn solution_2023_22_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_22_01(&std::fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2023_22_02(input: &str) -> anyhow::Result<usize> {
+    let mut wall = Wall::from_string(input)?;
     wall.settle();
     wall.build_support_graph();
     Ok(wall.count_supported_chained())
 }
 
+pub fn solution_2023_22_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_22_02(&std::fs::read_to_string(file_path)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
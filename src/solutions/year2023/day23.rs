@@ -1,5 +1,6 @@
 // Advent of Code 2023 - Day 23
 
+use crate::cancel;
 use std::collections::{HashMap, HashSet, VecDeque};
 
 enum Tile {
@@ -189,8 +190,9 @@ impl Forest {
     fn find_longest_path_in_graph(
         &self,
         graph: &HashMap<(usize, usize), Vec<((usize, usize), usize)>>,
-    ) -> usize {
+    ) -> anyhow::Result<usize> {
         let mut max_dist = 0;
+        let mut steps = 0u64;
         let mut stack = vec![(self.start, 0, 0u128)];
 
         // Map positions to bit indices
@@ -202,6 +204,10 @@ impl Forest {
         let start_bit = 1u128 << pos_to_bit[&self.start];
 
         while let Some((pos, dist, visited)) = stack.pop() {
+            steps += 1;
+            if steps.is_multiple_of(1 << 20) {
+                cancel::check()?;
+            }
             if pos == self.end {
                 max_dist = max_dist.max(dist);
                 continue;
@@ -217,7 +223,7 @@ impl Forest {
             }
         }
 
-        max_dist
+        Ok(max_dist)
     }
 }
 
@@ -257,22 +263,28 @@ impl Explorer {
     }
 }
 
-pub fn solution_2023_23_01(file_path: String) -> anyhow::Result<usize> {
-    let input = std::fs::read_to_string(file_path)?;
-    let forest = Forest::from_string(&input)?;
+pub fn solve_2023_23_01(input: &str) -> anyhow::Result<usize> {
+    let forest = Forest::from_string(input)?;
     let mut explorer = Explorer::new(forest.start);
     let longest_path_length = explorer.find_longest_path(&forest, false);
     Ok(longest_path_length)
 }
 
-pub fn solution_2023_23_02(file_path: String) -> anyhow::Result<usize> {
-    let input = std::fs::read_to_string(file_path)?;
-    let forest = Forest::from_string(&input)?;
+pub fn solution_2023_23_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_23_01(&std::fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2023_23_02(input: &str) -> anyhow::Result<usize> {
+    let forest = Forest::from_string(input)?;
     let graph = forest.build_graph(true);
-    let longest_path_length = forest.find_longest_path_in_graph(&graph);
+    let longest_path_length = forest.find_longest_path_in_graph(&graph)?;
     Ok(longest_path_length)
 }
 
+pub fn solution_2023_23_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_23_02(&std::fs::read_to_string(file_path)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
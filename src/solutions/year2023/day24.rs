@@ -171,11 +171,8 @@ fn solve_4x4(mut matrix: [[f64; 4]; 4], mut rhs: [f64; 4]) -> Option<[f64; 4]> {
 }
 
 /// Part 1: Count path intersections in 2D within test area
-pub fn solution_2023_24_01(file_path: String, range: (f64, f64)) -> anyhow::Result<usize> {
-    let particles: Vec<Particle> = std::fs::read_to_string(file_path)?
-        .lines()
-        .map(Particle::from_str)
-        .collect();
+pub fn solve_2023_24_01(input: &str, range: (f64, f64)) -> anyhow::Result<usize> {
+    let particles: Vec<Particle> = input.lines().map(Particle::from_str).collect();
 
     let mut intersection_count = 0;
     for i in 0..particles.len() {
@@ -189,6 +186,10 @@ pub fn solution_2023_24_01(file_path: String, range: (f64, f64)) -> anyhow::Resu
     Ok(intersection_count)
 }
 
+pub fn solution_2023_24_01(file_path: String, range: (f64, f64)) -> anyhow::Result<usize> {
+    solve_2023_24_01(&std::fs::read_to_string(file_path)?, range)
+}
+
 /// Part 2: Find rock position that collides with all particles
 ///
 /// Mathematical Approach:
@@ -202,8 +203,7 @@ pub fn solution_2023_24_01(file_path: String, range: (f64, f64)) -> anyhow::Resu
 ///
 /// This creates 4 linear equations for (xₛ, yₛ, vₓₛ, vᵧₛ) using 5 particles.
 /// Then solve for z separately using the computed times.
-pub fn solution_2023_24_02(file_path: String) -> anyhow::Result<usize> {
-    let input = std::fs::read_to_string(file_path)?;
+pub fn solve_2023_24_02(input: &str) -> anyhow::Result<usize> {
     let particles: Vec<Particle> = input.lines().map(Particle::from_str).collect();
 
     if particles.len() < 5 {
@@ -263,6 +263,10 @@ pub fn solution_2023_24_02(file_path: String) -> anyhow::Result<usize> {
     Ok(answer)
 }
 
+pub fn solution_2023_24_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_24_02(&std::fs::read_to_string(file_path)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
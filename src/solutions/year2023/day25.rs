@@ -137,8 +137,8 @@ impl Graph {
 }
 
 /// Part 1: Find 3 edges to cut to split graph into 2 components
-pub fn solution_2023_25_01(file_path: String) -> anyhow::Result<usize> {
-    let mut graph = Graph::from_str(&std::fs::read_to_string(file_path)?);
+pub fn solve_2023_25_01(input: &str) -> anyhow::Result<usize> {
+    let mut graph = Graph::from_str(input);
 
     let edge_usage = graph.count_edge_usage(50);
     // ]\
@@ -160,6 +160,10 @@ pub fn solution_2023_25_01(file_path: String) -> anyhow::Result<usize> {
     Ok(component1_size * component2_size)
 }
 
+pub fn solution_2023_25_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2023_25_01(&std::fs::read_to_string(file_path)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,5 @@
+use crate::registry::{phased, solution, Entry, Phased};
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -23,3 +25,74 @@ pub mod day22;
 pub mod day23;
 pub mod day24;
 pub mod day25;
+
+pub static SOLUTIONS: &[Entry] = &[
+    solution!(2023, 1, 1, day01::solve_2023_01_01),
+    solution!(2023, 1, 2, day01::solve_2023_01_02),
+    solution!(2023, 2, 1, day02::solve_2023_02_01),
+    solution!(2023, 2, 2, day02::solve_2023_02_02),
+    solution!(2023, 3, 1, day03::solve_2023_03_01),
+    solution!(2023, 3, 2, day03::solve_2023_03_02),
+    solution!(2023, 4, 1, day04::solve_2023_04_01),
+    solution!(2023, 4, 2, day04::solve_2023_04_02),
+    solution!(2023, 5, 1, day05::solve_2023_05_01),
+    solution!(2023, 5, 2, day05::solve_2023_05_02),
+    solution!(2023, 6, 1, day06::solve_2023_06_01),
+    solution!(2023, 6, 2, day06::solve_2023_06_02),
+    solution!(2023, 7, 1, day07::solve_2023_07_01),
+    solution!(2023, 7, 2, day07::solve_2023_07_02),
+    solution!(2023, 8, 1, day08::solve_2023_08_01),
+    solution!(2023, 8, 2, day08::solve_2023_08_02),
+    solution!(2023, 9, 1, day09::solve_2023_09_01),
+    solution!(2023, 9, 2, day09::solve_2023_09_02),
+    solution!(2023, 10, 1, day10::solve_2023_10_01),
+    solution!(2023, 10, 2, day10::solve_2023_10_02),
+    solution!(2023, 11, 1, day11::solve_2023_11, 2),
+    solution!(2023, 11, 2, day11::solve_2023_11, 1000000),
+    solution!(2023, 12, 1, day12::solve_2023_12_01),
+    solution!(2023, 12, 2, day12::solve_2023_12_02),
+    solution!(2023, 13, 1, day13::solve_2023_13_01),
+    solution!(2023, 13, 2, day13::solve_2023_13_02),
+    solution!(2023, 14, 1, day14::solve_2023_14_01),
+    solution!(2023, 14, 2, day14::solve_2023_14_02),
+    solution!(2023, 15, 1, day15::solve_2023_15_01),
+    solution!(2023, 15, 2, day15::solve_2023_15_02),
+    solution!(2023, 16, 1, day16::solve_2023_16_01),
+    solution!(2023, 16, 2, day16::solve_2023_16_02),
+    solution!(2023, 17, 1, day17::solve_2023_17_01),
+    solution!(2023, 17, 2, day17::solve_2023_17_02),
+    solution!(2023, 18, 1, day18::solve_2023_18_01),
+    solution!(2023, 18, 2, day18::solve_2023_18_02),
+    solution!(2023, 19, 1, day19::solve_2023_19_01),
+    solution!(2023, 19, 2, day19::solve_2023_19_02),
+    solution!(2023, 20, 1, day20::solve_2023_20_01),
+    solution!(2023, 20, 2, day20::solve_2023_20_02),
+    solution!(2023, 21, 1, day21::solve_2023_21_01, 64),
+    solution!(2023, 21, 2, day21::solve_2023_21_02, 26501365),
+    solution!(2023, 22, 1, day22::solve_2023_22_01),
+    solution!(2023, 22, 2, day22::solve_2023_22_02),
+    solution!(2023, 23, 1, day23::solve_2023_23_01),
+    solution!(2023, 23, 2, day23::solve_2023_23_02),
+    solution!(
+        2023,
+        24,
+        1,
+        day24::solve_2023_24_01,
+        (200000000000000.0, 400000000000000.0)
+    ),
+    solution!(2023, 24, 2, day24::solve_2023_24_02),
+    solution!(2023, 25, 1, day25::solve_2023_25_01),
+];
+
+pub static PHASED: &[Phased] = &[
+    phased::<day03::Day03>(2023, 3),
+    phased::<day05::Day05>(2023, 5),
+    phased::<day08::Day08>(2023, 8),
+    phased::<day09::Day09>(2023, 9),
+    phased::<day10::Day10>(2023, 10),
+    phased::<day14::Day14>(2023, 14),
+    phased::<day17::Day17>(2023, 17),
+    phased::<day18::Day18>(2023, 18),
+    phased::<day19::Day19>(2023, 19),
+    phased::<day20::Day20>(2023, 20),
+];
@@ -6,25 +6,21 @@ fn parse_line(line: &str) -> Vec<i32> {
         .unwrap()
 }
 
-pub fn parse_input(filepath: String) -> Vec<Vec<i32>> {
+pub fn parse_input(input: &str) -> Vec<Vec<i32>> {
     let mut left = vec![];
     let mut right = vec![];
-    std::fs::read_to_string(filepath)
-        .expect("Invalid file")
-        .trim_end()
-        .lines()
-        .for_each(|line| {
-            let vals = parse_line(line);
-            left.push(vals[0]);
-            right.push(vals[1]);
-        });
+    input.trim_end().lines().for_each(|line| {
+        let vals = parse_line(line);
+        left.push(vals[0]);
+        right.push(vals[1]);
+    });
     left.sort();
     right.sort();
     vec![left, right]
 }
 
-pub fn solution_2024_01_01(filepath: String) -> Option<i32> {
-    let input = parse_input(filepath);
+pub fn solve_2024_01_01(input: &str) -> Option<i32> {
+    let input = parse_input(input);
     let result = input[0]
         .iter()
         .zip(input[1].iter())
@@ -32,8 +28,12 @@ pub fn solution_2024_01_01(filepath: String) -> Option<i32> {
     Some(result)
 }
 
-pub fn solution_2024_01_02(filepath: String) -> Option<i32> {
-    let input = parse_input(filepath);
+pub fn solution_2024_01_01(filepath: String) -> Option<i32> {
+    solve_2024_01_01(&std::fs::read_to_string(filepath).expect("Invalid file"))
+}
+
+pub fn solve_2024_01_02(input: &str) -> Option<i32> {
+    let input = parse_input(input);
     let result = input[0].iter().fold(0, |acc, key| {
         let count = input[1].iter().filter(|&v| v == key).count();
         acc + (count as i32 * key)
@@ -41,6 +41,10 @@ pub fn solution_2024_01_02(filepath: String) -> Option<i32> {
     Some(result)
 }
 
+pub fn solution_2024_01_02(filepath: String) -> Option<i32> {
+    solve_2024_01_02(&std::fs::read_to_string(filepath).expect("Invalid file"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
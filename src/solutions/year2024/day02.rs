@@ -1,6 +1,5 @@
-fn parse_input(filepath: &str) -> Vec<Vec<i32>> {
-    std::fs::read_to_string(filepath)
-        .expect("Invalid file")
+fn parse_input(input: &str) -> Vec<Vec<i32>> {
+    input
         .trim_end()
         .lines()
         .map(|l| l.split_whitespace().map(|s| s.parse().unwrap()).collect())
@@ -52,16 +51,24 @@ fn is_safe_with_dampner(report: &Vec<i32>) -> bool {
     false
 }
 
-pub fn solution_2024_02_01(filepath: String) -> Option<i32> {
-    let input = parse_input(&filepath);
+pub fn solve_2024_02_01(input: &str) -> Option<i32> {
+    let input = parse_input(input);
     Some(input.into_iter().filter(is_safe).count() as i32)
 }
 
-pub fn solution_2024_02_02(filepath: String) -> Option<i32> {
-    let input = parse_input(&filepath);
+pub fn solution_2024_02_01(filepath: String) -> Option<i32> {
+    solve_2024_02_01(&std::fs::read_to_string(filepath).expect("Invalid file"))
+}
+
+pub fn solve_2024_02_02(input: &str) -> Option<i32> {
+    let input = parse_input(input);
     Some(input.into_iter().filter(is_safe_with_dampner).count() as i32)
 }
 
+pub fn solution_2024_02_02(filepath: String) -> Option<i32> {
+    solve_2024_02_02(&std::fs::read_to_string(filepath).expect("Invalid file"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,21 +1,23 @@
 use regex::Regex;
 
-pub fn solution_2024_03_01(filepath: String) -> Option<i32> {
+pub fn solve_2024_03_01(input: &str) -> Option<i32> {
     let mul_regex = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
-    let result = mul_regex
-        .captures_iter(&std::fs::read_to_string(filepath).unwrap())
-        .fold(0, |acc, captures| {
-            let (_, [num1, num2]) = captures.extract();
-            acc + (num1.parse::<i32>().unwrap() * num2.parse::<i32>().unwrap())
-        });
+    let result = mul_regex.captures_iter(input).fold(0, |acc, captures| {
+        let (_, [num1, num2]) = captures.extract();
+        acc + (num1.parse::<i32>().unwrap() * num2.parse::<i32>().unwrap())
+    });
     Some(result)
 }
 
-pub fn solution_2024_03_02(filepath: String) -> Option<i32> {
+pub fn solution_2024_03_01(filepath: String) -> Option<i32> {
+    solve_2024_03_01(&std::fs::read_to_string(filepath).unwrap())
+}
+
+pub fn solve_2024_03_02(input: &str) -> Option<i32> {
     let instructions_regex = Regex::new(r"(mul\((\d+),(\d+)\))|(don't\(\))|(do\(\))").unwrap();
     let mut enabled = true;
     let result = instructions_regex
-        .captures_iter(&std::fs::read_to_string(filepath).unwrap())
+        .captures_iter(input)
         .fold(0, |acc, captures| {
             let name = captures.get(0).unwrap().as_str();
             match name {
@@ -34,6 +36,10 @@ pub fn solution_2024_03_02(filepath: String) -> Option<i32> {
     Some(result)
 }
 
+pub fn solution_2024_03_02(filepath: String) -> Option<i32> {
+    solve_2024_03_02(&std::fs::read_to_string(filepath).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
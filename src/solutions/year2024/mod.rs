@@ -1,3 +1,14 @@
+use crate::registry::{solution, Entry};
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
+
+pub static SOLUTIONS: &[Entry] = &[
+    solution!(2024, 1, 1, day01::solve_2024_01_01),
+    solution!(2024, 1, 2, day01::solve_2024_01_02),
+    solution!(2024, 2, 1, day02::solve_2024_02_01),
+    solution!(2024, 2, 2, day02::solve_2024_02_02),
+    solution!(2024, 3, 1, day03::solve_2024_03_01),
+    solution!(2024, 3, 2, day03::solve_2024_03_02),
+];
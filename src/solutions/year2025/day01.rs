@@ -22,42 +22,42 @@ fn get_dial_position(current_pos: i32, offset: i32, dial_size: i32) -> (i32, i32
 const DIAL_SIZE: i32 = 100;
 const STARTING_POSITION: i32 = 50;
 
+pub fn solve_2025_01_01(input: &str) -> Result<i32, Box<dyn std::error::Error>> {
+    let (_, zero_count) = input.trim_end().lines().map(parse_instruction).fold(
+        (STARTING_POSITION, 0),
+        |(current_position, zero_count), ins| {
+            let (new_position, _) = get_dial_position(current_position, ins, DIAL_SIZE);
+            let mut zero_count = zero_count;
+            if new_position == 0 {
+                zero_count += 1;
+            };
+            (new_position, zero_count)
+        },
+    );
+    Ok(zero_count)
+}
+
 pub fn solution_2025_01_01(filepath: String) -> Result<i32, Box<dyn std::error::Error>> {
-    let (_, zero_count) = std::fs::read_to_string(filepath)?
-        .trim_end()
-        .lines()
-        .map(parse_instruction)
-        .fold(
-            (STARTING_POSITION, 0),
-            |(current_position, zero_count), ins| {
-                let (new_position, _) = get_dial_position(current_position, ins, DIAL_SIZE);
-                let mut zero_count = zero_count;
-                if new_position == 0 {
-                    zero_count += 1;
-                };
-                (new_position, zero_count)
-            },
-        );
+    solve_2025_01_01(&std::fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2025_01_02(input: &str) -> Result<i32, Box<dyn std::error::Error>> {
+    let (_, zero_count) = input.trim_end().lines().map(parse_instruction).fold(
+        (STARTING_POSITION, 0),
+        |(current_position, zero_count), ins| {
+            let (new_position, rotations) = get_dial_position(current_position, ins, DIAL_SIZE);
+            let mut zero_count = zero_count + rotations;
+            if new_position == 0 && rotations == 0 {
+                zero_count += 1;
+            }
+            (new_position, zero_count)
+        },
+    );
     Ok(zero_count)
 }
 
 pub fn solution_2025_01_02(filepath: String) -> Result<i32, Box<dyn std::error::Error>> {
-    let (_, zero_count) = std::fs::read_to_string(filepath)?
-        .trim_end()
-        .lines()
-        .map(parse_instruction)
-        .fold(
-            (STARTING_POSITION, 0),
-            |(current_position, zero_count), ins| {
-                let (new_position, rotations) = get_dial_position(current_position, ins, DIAL_SIZE);
-                let mut zero_count = zero_count + rotations;
-                if new_position == 0 && rotations == 0 {
-                    zero_count += 1;
-                }
-                (new_position, zero_count)
-            },
-        );
-    Ok(zero_count)
+    solve_2025_01_02(&std::fs::read_to_string(filepath)?)
 }
 
 #[cfg(test)]
@@ -35,8 +35,8 @@ fn get_range(range_string: &str) -> impl Iterator<Item = usize> {
     start..=end
 }
 
-pub fn solution_2025_02_01(filepath: String) -> Result<usize, Box<dyn std::error::Error>> {
-    let result = std::fs::read_to_string(filepath)?
+pub fn solve_2025_02_01(input: &str) -> Result<usize, Box<dyn std::error::Error>> {
+    let result = input
         .trim_end()
         .split(',')
         .map(|range_str| {
@@ -48,8 +48,12 @@ pub fn solution_2025_02_01(filepath: String) -> Result<usize, Box<dyn std::error
     Ok(result)
 }
 
-pub fn solution_2025_02_02(filepath: String) -> Result<usize, Box<dyn std::error::Error>> {
-    let result = std::fs::read_to_string(filepath)?
+pub fn solution_2025_02_01(filepath: String) -> Result<usize, Box<dyn std::error::Error>> {
+    solve_2025_02_01(&std::fs::read_to_string(filepath)?)
+}
+
+pub fn solve_2025_02_02(input: &str) -> Result<usize, Box<dyn std::error::Error>> {
+    let result = input
         .trim_end()
         .split(',')
         .map(|range_str| {
@@ -61,6 +65,10 @@ pub fn solution_2025_02_02(filepath: String) -> Result<usize, Box<dyn std::error
     Ok(result)
 }
 
+pub fn solution_2025_02_02(filepath: String) -> Result<usize, Box<dyn std::error::Error>> {
+    solve_2025_02_02(&std::fs::read_to_string(filepath)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -24,22 +24,22 @@ fn get_jolts(val: &str, digits: usize) -> usize {
     })
 }
 
+pub fn solve_2025_03_01(input: &str) -> anyhow::Result<usize> {
+    let res = input.lines().map(|line| get_jolts(line, 2)).sum();
+    Ok(res)
+}
+
 pub fn solution_2025_03_01(file_path: String) -> anyhow::Result<usize> {
-    let res = fs::read_to_string(file_path)
-        .expect("Invalid file")
-        .lines()
-        .map(|line| get_jolts(line, 2))
-        .sum();
+    solve_2025_03_01(&fs::read_to_string(file_path).expect("Invalid file"))
+}
+
+pub fn solve_2025_03_02(input: &str) -> anyhow::Result<usize> {
+    let res = input.lines().map(|line| get_jolts(line, 12)).sum();
     Ok(res)
 }
 
 pub fn solution_2025_03_02(file_path: String) -> anyhow::Result<usize> {
-    let res = fs::read_to_string(file_path)
-        .expect("Invalid file")
-        .lines()
-        .map(|line| get_jolts(line, 12))
-        .sum();
-    Ok(res)
+    solve_2025_03_02(&fs::read_to_string(file_path).expect("Invalid file"))
 }
 
 #[cfg(test)]
@@ -71,14 +71,17 @@ impl Diagram {
     }
 }
 
-pub fn solution_2025_04_01(file_path: String) -> anyhow::Result<usize> {
-    let diagram = Diagram::from_string(&fs::read_to_string(file_path).expect("Input not found"));
+pub fn solve_2025_04_01(input: &str) -> anyhow::Result<usize> {
+    let diagram = Diagram::from_string(input);
     Ok(diagram.removable().len())
 }
 
-pub fn solution_2025_04_02(file_path: String) -> anyhow::Result<usize> {
-    let mut diagram =
-        Diagram::from_string(&fs::read_to_string(file_path).expect("Input not found"));
+pub fn solution_2025_04_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2025_04_01(&fs::read_to_string(file_path).expect("Input not found"))
+}
+
+pub fn solve_2025_04_02(input: &str) -> anyhow::Result<usize> {
+    let mut diagram = Diagram::from_string(input);
     let mut result = 0;
     loop {
         let c = diagram.remove_some();
@@ -90,6 +93,10 @@ pub fn solution_2025_04_02(file_path: String) -> anyhow::Result<usize> {
     Ok(result)
 }
 
+pub fn solution_2025_04_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2025_04_02(&fs::read_to_string(file_path).expect("Input not found"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -20,8 +20,8 @@ fn parse(inp: &str) -> (Vec<Range<usize>>, Vec<usize>) {
     (ranges_section, ids_section)
 }
 
-pub fn solution_2025_05_01(file_path: String) -> anyhow::Result<usize> {
-    let (ranges, ids) = parse(&std::fs::read_to_string(file_path)?);
+pub fn solve_2025_05_01(input: &str) -> anyhow::Result<usize> {
+    let (ranges, ids) = parse(input);
     let result = ids
         .iter()
         .filter(|&&id| {
@@ -36,12 +36,20 @@ pub fn solution_2025_05_01(file_path: String) -> anyhow::Result<usize> {
     Ok(result)
 }
 
-pub fn solution_2025_05_02(file_path: String) -> anyhow::Result<usize> {
-    let (ranges, _) = parse(&std::fs::read_to_string(file_path)?);
+pub fn solution_2025_05_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2025_05_01(&std::fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2025_05_02(input: &str) -> anyhow::Result<usize> {
+    let (ranges, _) = parse(input);
     let compact_range = Range::compact(ranges);
     Ok(compact_range.iter().map(|r| r.length() + 1).sum())
 }
 
+pub fn solution_2025_05_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2025_05_02(&std::fs::read_to_string(file_path)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
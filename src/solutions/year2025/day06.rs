@@ -72,16 +72,24 @@ fn parse_input(inp: &str) -> anyhow::Result<Vec<Group>> {
     Ok(groups)
 }
 
-pub fn solution_2025_06_01(file_path: String) -> anyhow::Result<usize> {
-    let groups = parse_input(&std::fs::read_to_string(file_path)?)?;
+pub fn solve_2025_06_01(input: &str) -> anyhow::Result<usize> {
+    let groups = parse_input(input)?;
     Ok(groups.iter().filter_map(|g| g.evaluate(false)).sum())
 }
 
-pub fn solution_2025_06_02(file_path: String) -> anyhow::Result<usize> {
-    let groups = parse_input(&std::fs::read_to_string(file_path)?)?;
+pub fn solution_2025_06_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2025_06_01(&std::fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2025_06_02(input: &str) -> anyhow::Result<usize> {
+    let groups = parse_input(input)?;
     Ok(groups.iter().filter_map(|g| g.evaluate(true)).sum())
 }
 
+pub fn solution_2025_06_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2025_06_02(&std::fs::read_to_string(file_path)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
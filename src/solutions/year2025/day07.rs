@@ -95,16 +95,24 @@ impl TachyonManifold {
     }
 }
 
-pub fn solution_2025_07_01(file_path: String) -> anyhow::Result<usize> {
-    let manifold = TachyonManifold::from_str(&std::fs::read_to_string(file_path)?);
+pub fn solve_2025_07_01(input: &str) -> anyhow::Result<usize> {
+    let manifold = TachyonManifold::from_str(input);
     Ok(manifold.count_splits())
 }
 
-pub fn solution_2025_07_02(file_path: String) -> anyhow::Result<usize> {
-    let manifold = TachyonManifold::from_str(&std::fs::read_to_string(file_path)?);
+pub fn solution_2025_07_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2025_07_01(&std::fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2025_07_02(input: &str) -> anyhow::Result<usize> {
+    let manifold = TachyonManifold::from_str(input);
     Ok(manifold.count_timeline())
 }
 
+pub fn solution_2025_07_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2025_07_02(&std::fs::read_to_string(file_path)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
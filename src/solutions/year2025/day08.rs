@@ -168,11 +168,8 @@ impl Circuit {
     }
 }
 
-pub fn solution_2025_08_01(
-    file_path: String,
-    connection_sample_size: usize,
-) -> anyhow::Result<usize> {
-    let mut circuit = Circuit::from_str(&std::fs::read_to_string(file_path)?);
+pub fn solve_2025_08_01(input: &str, connection_sample_size: usize) -> anyhow::Result<usize> {
+    let mut circuit = Circuit::from_str(input);
     circuit.build_distance_matrix();
     let binding = circuit.distance_matrix.clone();
     let mut matrix = binding.iter().collect::<Vec<_>>();
@@ -201,8 +198,15 @@ pub fn solution_2025_08_01(
     Ok(circuit_lengths.iter().take(3).product())
 }
 
-pub fn solution_2025_08_02(file_path: String) -> anyhow::Result<usize> {
-    let mut circuit = Circuit::from_str(&std::fs::read_to_string(file_path)?);
+pub fn solution_2025_08_01(
+    file_path: String,
+    connection_sample_size: usize,
+) -> anyhow::Result<usize> {
+    solve_2025_08_01(&std::fs::read_to_string(file_path)?, connection_sample_size)
+}
+
+pub fn solve_2025_08_02(input: &str) -> anyhow::Result<usize> {
+    let mut circuit = Circuit::from_str(input);
     circuit.build_distance_matrix();
 
     // Get all edges sorted by distance
@@ -235,6 +239,10 @@ pub fn solution_2025_08_02(file_path: String) -> anyhow::Result<usize> {
     }
 }
 
+pub fn solution_2025_08_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2025_08_02(&std::fs::read_to_string(file_path)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -265,6 +273,6 @@ mod tests {
     fn output_2025_08_02() {
         let file_path: String = String::from("inputs/2025/day08.txt");
         let result = solution_2025_08_02(file_path).unwrap();
-        assert_eq!(1, 51294528);
+        assert_eq!(result, 51294528);
     }
 }
@@ -185,14 +185,22 @@ impl Floor {
 
 /// # Errors
 /// Returns an error if the file cannot be read or parsed.
+pub fn solve_2025_09_01(input: &str) -> anyhow::Result<usize> {
+    Ok(Floor::from_str(input).get_largest_area())
+}
+
 pub fn solution_2025_09_01(file_path: String) -> anyhow::Result<usize> {
-    Ok(Floor::from_str(&std::fs::read_to_string(file_path)?).get_largest_area())
+    solve_2025_09_01(&std::fs::read_to_string(file_path)?)
 }
 
 /// # Errors
 /// Returns an error if the file cannot be read or parsed.
+pub fn solve_2025_09_02(input: &str) -> anyhow::Result<usize> {
+    Ok(Floor::from_str(input).get_largest_area_in_hull())
+}
+
 pub fn solution_2025_09_02(file_path: String) -> anyhow::Result<usize> {
-    Ok(Floor::from_str(&std::fs::read_to_string(file_path)?).get_largest_area_in_hull())
+    solve_2025_09_02(&std::fs::read_to_string(file_path)?)
 }
 
 #[cfg(test)]
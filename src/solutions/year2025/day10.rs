@@ -273,8 +273,8 @@ impl Machine {
     }
 }
 
-pub fn solution_2025_10_01(file_path: String) -> anyhow::Result<usize> {
-    let machines = std::fs::read_to_string(file_path)?
+pub fn solve_2025_10_01(input: &str) -> anyhow::Result<usize> {
+    let machines = input
         .lines()
         .map(Instruction::from_str)
         .map(Machine::new)
@@ -285,11 +285,15 @@ pub fn solution_2025_10_01(file_path: String) -> anyhow::Result<usize> {
         .sum())
 }
 
-pub fn solution_2025_10_02(file_path: String) -> anyhow::Result<usize> {
-    let machines = std::fs::read_to_string(file_path)?
+pub fn solution_2025_10_01(file_path: String) -> anyhow::Result<usize> {
+    solve_2025_10_01(&std::fs::read_to_string(file_path)?)
+}
+
+pub fn solve_2025_10_02(input: &str) -> anyhow::Result<usize> {
+    let machines = input
         .lines()
         .map(Instruction::from_str)
-        .map(Machinee:new)
+        .map(Machine::new)
         .collect::<Vec<Machine>>();
     Ok(machines
         .into_iter()
@@ -297,6 +301,10 @@ pub fn solution_2025_10_02(file_path: String) -> anyhow::Result<usize> {
         .sum())
 }
 
+pub fn solution_2025_10_02(file_path: String) -> anyhow::Result<usize> {
+    solve_2025_10_02(&std::fs::read_to_string(file_path)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
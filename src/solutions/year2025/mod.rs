@@ -1,3 +1,5 @@
+use crate::registry::{solution, Entry};
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -8,3 +10,26 @@ pub mod day07;
 pub mod day08;
 pub mod day09;
 pub mod day10;
+
+pub static SOLUTIONS: &[Entry] = &[
+    solution!(2025, 1, 1, day01::solve_2025_01_01),
+    solution!(2025, 1, 2, day01::solve_2025_01_02),
+    solution!(2025, 2, 1, day02::solve_2025_02_01),
+    solution!(2025, 2, 2, day02::solve_2025_02_02),
+    solution!(2025, 3, 1, day03::solve_2025_03_01),
+    solution!(2025, 3, 2, day03::solve_2025_03_02),
+    solution!(2025, 4, 1, day04::solve_2025_04_01),
+    solution!(2025, 4, 2, day04::solve_2025_04_02),
+    solution!(2025, 5, 1, day05::solve_2025_05_01),
+    solution!(2025, 5, 2, day05::solve_2025_05_02),
+    solution!(2025, 6, 1, day06::solve_2025_06_01),
+    solution!(2025, 6, 2, day06::solve_2025_06_02),
+    solution!(2025, 7, 1, day07::solve_2025_07_01),
+    solution!(2025, 7, 2, day07::solve_2025_07_02),
+    solution!(2025, 8, 1, day08::solve_2025_08_01, 1000),
+    solution!(2025, 8, 2, day08::solve_2025_08_02),
+    solution!(2025, 9, 1, day09::solve_2025_09_01),
+    solution!(2025, 9, 2, day09::solve_2025_09_02),
+    solution!(2025, 10, 1, day10::solve_2025_10_01),
+    solution!(2025, 10, 2, day10::solve_2025_10_02),
+];
@@ -0,0 +1,326 @@
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+
+use crate::utils::collections::Faux2DArray;
+
+const BITS: usize = u64::BITS as usize;
+
+/// A fixed size grid of booleans packed 64 to a word, each row starting on a
+/// new word. Rectangle updates and counts work a word at a time, and
+/// [`BitGrid::step`] advances a cellular automaton on whole words.
+///
+/// Bits past the last column are always clear, and positions outside the
+/// grid read as clear.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// A grid with every bit clear.
+    pub fn new(width: usize, height: usize) -> BitGrid {
+        let words_per_row = width.div_ceil(BITS);
+        BitGrid {
+            width,
+            height,
+            words_per_row,
+            words: vec![0; words_per_row * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn row(&self, y: usize) -> &[u64] {
+        &self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.row(y)[x / BITS] >> (x % BITS) & 1 == 1
+    }
+
+    /// Panics if `(x, y)` is outside the grid.
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the grid",
+            x,
+            y
+        );
+        let word = &mut self.words[y * self.words_per_row + x / BITS];
+        let bit = 1 << (x % BITS);
+        if value {
+            *word |= bit;
+        } else {
+            *word &= !bit;
+        }
+    }
+
+    /// Panics if `(x, y)` is outside the grid.
+    pub fn toggle(&mut self, x: usize, y: usize) {
+        let value = self.get(x, y);
+        self.set(x, y, !value);
+    }
+
+    /// Sets every bit in the rectangle, e.g. `set_rect(0..=999, 0..=999)`.
+    /// Parts of the rectangle outside the grid are ignored.
+    pub fn set_rect(&mut self, xs: impl RangeBounds<usize>, ys: impl RangeBounds<usize>) {
+        self.update_rect(xs, ys, |word, mask| word | mask);
+    }
+
+    /// Flips every bit in the rectangle.
+    pub fn toggle_rect(&mut self, xs: impl RangeBounds<usize>, ys: impl RangeBounds<usize>) {
+        self.update_rect(xs, ys, |word, mask| word ^ mask);
+    }
+
+    /// Clears every bit in the rectangle.
+    pub fn clear_rect(&mut self, xs: impl RangeBounds<usize>, ys: impl RangeBounds<usize>) {
+        self.update_rect(xs, ys, |word, mask| word & !mask);
+    }
+
+    /// Applies `update(word, mask)` to every word the rectangle covers, `mask`
+    /// having the bits of the word inside the rectangle set.
+    fn update_rect<F>(
+        &mut self,
+        xs: impl RangeBounds<usize>,
+        ys: impl RangeBounds<usize>,
+        update: F,
+    ) where
+        F: Fn(u64, u64) -> u64,
+    {
+        let (x_start, x_end) = bounds(xs, self.width);
+        let (y_start, y_end) = bounds(ys, self.height);
+        if x_start >= x_end {
+            return;
+        }
+        let (first, last) = (x_start / BITS, (x_end - 1) / BITS);
+        for y in y_start..y_end {
+            let row = y * self.words_per_row;
+            for i in first..=last {
+                let low = if i == first { x_start % BITS } else { 0 };
+                let high = if i == last {
+                    (x_end - 1) % BITS
+                } else {
+                    BITS - 1
+                };
+                let mask = (u64::MAX >> (BITS - 1 - high)) & (u64::MAX << low);
+                self.words[row + i] = update(self.words[row + i], mask);
+            }
+        }
+    }
+
+    /// Number of bits set.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Set neighbours of every cell, diagonals included, as four bit planes
+    /// per word: bit `b` of the count is in plane `b`.
+    fn neighbor_planes(&self) -> Vec<[u64; 4]> {
+        let mut planes = vec![[0; 4]; self.words.len()];
+        let empty = vec![0; self.words_per_row];
+        for y in 0..self.height {
+            let above = if y > 0 { self.row(y - 1) } else { &empty };
+            let below = if y + 1 < self.height {
+                self.row(y + 1)
+            } else {
+                &empty
+            };
+            let row = self.row(y);
+            for i in 0..self.words_per_row {
+                let counts = &mut planes[y * self.words_per_row + i];
+                for (words, centre) in [(above, true), (row, false), (below, true)] {
+                    // The neighbour to the left of bit x is bit x - 1, which
+                    // moves up a bit, carrying across words
+                    let prev = if i > 0 { words[i - 1] >> (BITS - 1) } else { 0 };
+                    let next = words.get(i + 1).map_or(0, |w| w << (BITS - 1));
+                    add(counts, words[i] << 1 | prev);
+                    add(counts, words[i] >> 1 | next);
+                    if centre {
+                        add(counts, words[i]);
+                    }
+                }
+            }
+        }
+        planes
+    }
+
+    /// Number of set neighbours of every cell, diagonals included.
+    pub fn neighbor_counts(&self) -> Faux2DArray<u8> {
+        let planes = self.neighbor_planes();
+        Faux2DArray::filled(self.width, self.height, |x, y| {
+            let counts = &planes[y * self.words_per_row + x / BITS];
+            (0..4)
+                .map(|b| ((counts[b] >> (x % BITS) & 1) as u8) << b)
+                .sum()
+        })
+    }
+
+    /// One step of a life-like cellular automaton: a clear cell becomes set
+    /// with a number of set neighbours in `born`, a set cell stays set with
+    /// one in `survives`. Conway's Game of Life is `step(&[3], &[2, 3])`.
+    pub fn step(&self, born: &[u8], survives: &[u8]) -> BitGrid {
+        let planes = self.neighbor_planes();
+        let mut next = BitGrid::new(self.width, self.height);
+        for (i, (&word, counts)) in self.words.iter().zip(&planes).enumerate() {
+            let with = |n: u8| {
+                (0..4).fold(u64::MAX, |mask, b| match n >> b & 1 {
+                    1 => mask & counts[b],
+                    _ => mask & !counts[b],
+                })
+            };
+            let born = born.iter().fold(0, |mask, &n| mask | with(n));
+            let survives = survives.iter().fold(0, |mask, &n| mask | with(n));
+            next.words[i] = (!word & born) | (word & survives);
+        }
+        next.clear_padding();
+        next
+    }
+
+    /// Clears the bits past the last column, which shifting may have set.
+    fn clear_padding(&mut self) {
+        let used = self.width % BITS;
+        if used == 0 {
+            return;
+        }
+        for row in self.words.chunks_mut(self.words_per_row) {
+            row[self.words_per_row - 1] &= (1 << used) - 1;
+        }
+    }
+
+    pub fn to_grid(&self) -> Faux2DArray<bool> {
+        Faux2DArray::filled(self.width, self.height, |x, y| self.get(x, y))
+    }
+}
+
+/// Adds `bits` to the bit-sliced counters in `planes`, one per bit position.
+fn add(planes: &mut [u64; 4], bits: u64) {
+    let mut carry = bits;
+    for plane in planes {
+        let next = *plane & carry;
+        *plane ^= carry;
+        carry = next;
+    }
+}
+
+/// `range` as a start and exclusive end, clamped to `0..len`.
+fn bounds(range: impl RangeBounds<usize>, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e + 1,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+    (start.min(len), end.min(len))
+}
+
+impl From<&Faux2DArray<bool>> for BitGrid {
+    fn from(grid: &Faux2DArray<bool>) -> Self {
+        let mut bits = BitGrid::new(grid.width, grid.height());
+        for (i, &value) in grid.items.iter().enumerate() {
+            if value {
+                let (x, y) = grid.cartesian_index(i);
+                bits.set(x, y, true);
+            }
+        }
+        bits
+    }
+}
+
+impl fmt::Display for BitGrid {
+    /// `#` for set bits and `.` for clear ones, a line per row.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                write!(f, "{}", if self.get(x, y) { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(grid: &str) -> BitGrid {
+        let grid: Faux2DArray<char> = grid.parse().unwrap();
+        let bools: Faux2DArray<bool> = Faux2DArray::filled(grid.width, grid.height(), |x, y| {
+            grid.at(x, y) == Some(&'#')
+        });
+        BitGrid::from(&bools)
+    }
+
+    #[test]
+    fn test_rects() {
+        // Wide enough for rectangles to span several words
+        let mut grid = BitGrid::new(200, 3);
+        grid.set_rect(10..=150, 0..2);
+        assert_eq!(grid.count_ones(), 141 * 2);
+        grid.toggle_rect(100..=199, 1..=2);
+        assert_eq!(grid.count_ones(), 141 * 2 - 51 + 49 + 100);
+        grid.clear_rect(.., 0..1);
+        assert_eq!(grid.count_ones(), 141 - 51 + 49 + 100);
+        assert!(grid.get(10, 1) && !grid.get(100, 1) && grid.get(199, 2));
+        assert!(!grid.get(200, 2));
+
+        // Clipped to the grid
+        grid.set_rect(190..300, ..);
+        assert_eq!(grid.row(2)[3], (1 << 8) - 1);
+        assert_eq!(grid.count_ones(), 141 - 51 + 49 + 100 + 10);
+    }
+
+    #[test]
+    fn test_neighbor_counts() {
+        let grid = parse("#.#\n.#.\n###\n");
+        assert_eq!(
+            grid.neighbor_counts().items,
+            vec![1, 3, 1, 4, 5, 4, 2, 3, 2]
+        );
+
+        // Across a word boundary
+        let mut wide = BitGrid::new(130, 2);
+        wide.set(63, 0, true);
+        wide.set(64, 1, true);
+        let counts = wide.neighbor_counts();
+        assert_eq!(counts.at(62, 1), Some(&1));
+        assert_eq!(counts.at(63, 1), Some(&2));
+        assert_eq!(counts.at(64, 0), Some(&2));
+        assert_eq!(counts.at(65, 0), Some(&1));
+        assert_eq!(counts.at(129, 1), Some(&0));
+    }
+
+    #[test]
+    fn test_step() {
+        let blinker = parse(".....\n..#..\n..#..\n..#..\n.....\n");
+        let turned = blinker.step(&[3], &[2, 3]);
+        assert_eq!(turned.to_string(), ".....\n.....\n.###.\n.....\n.....\n");
+        assert_eq!(turned.step(&[3], &[2, 3]), blinker);
+
+        // Nothing is born past the last column
+        let edge = parse(".#\n.#\n.#\n");
+        let stepped = edge.step(&[3], &[2, 3]);
+        assert_eq!(stepped.to_string(), "..\n##\n..\n");
+        assert_eq!(stepped.count_ones(), 2);
+    }
+
+    #[test]
+    fn test_grid_conversion() {
+        let grid = parse("#..#\n.##.\n");
+        assert_eq!(grid.count_ones(), 4);
+        assert_eq!(BitGrid::from(&grid.to_grid()), grid);
+    }
+}
@@ -1,5 +1,10 @@
 use std::fmt::{self, Debug};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
 
+use crate::utils::geometry::{Dir, Pos};
+
+#[derive(Clone, PartialEq, Eq)]
 pub struct Faux2DArray<T> {
     pub width: usize,
     pub items: Vec<T>,
@@ -272,6 +277,664 @@ impl<T> Faux2DArray<T> {
             })
         })
     }
+
+    /// Whether `pos` lies on the grid.
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x >= 0
+            && pos.y >= 0
+            && (pos.x as usize) < self.width
+            && (pos.y as usize) < self.height()
+    }
+
+    /// Index into `items` of `pos`, if it lies on the grid.
+    pub fn index_of(&self, pos: Pos) -> Option<usize> {
+        self.contains(pos)
+            .then(|| self.absolute_index(pos.x as usize, pos.y as usize))
+    }
+
+    /// Position of the item at `index` in `items`.
+    pub fn pos_of(&self, index: usize) -> Pos {
+        self.cartesian_index(index).into()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.items[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.items[i])
+    }
+
+    /// The position one step from `pos` in `dir`, if still on the grid.
+    pub fn step(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        let next = pos + dir;
+        self.contains(next).then_some(next)
+    }
+
+    /// The orthogonally adjacent positions on the grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbors4().filter(|&p| self.contains(p))
+    }
+
+    /// The adjacent positions on the grid, diagonals included.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbors8().filter(|&p| self.contains(p))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        (0..self.items.len()).map(|i| self.pos_of(i))
+    }
+
+    /// Position of the first item, row by row, matching `predicate`, e.g. a
+    /// start marker.
+    pub fn find<F>(&self, predicate: F) -> Option<Pos>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.items
+            .iter()
+            .position(predicate)
+            .map(|i| self.pos_of(i))
+    }
+
+    /// The whole grid seen through `transform`, without copying it.
+    pub fn view(&self, transform: Transform) -> View<'_, T> {
+        View {
+            grid: self,
+            frame: Frame::new(0, 0, self.width, self.height(), transform),
+        }
+    }
+
+    pub fn view_mut(&mut self, transform: Transform) -> ViewMut<'_, T> {
+        let frame = Frame::new(0, 0, self.width, self.height(), transform);
+        ViewMut { grid: self, frame }
+    }
+
+    /// The `width` by `height` rectangle starting at `top_left`, if it fits
+    /// on the grid. Positions in the window start again from (0, 0).
+    pub fn window(&self, top_left: Pos, width: usize, height: usize) -> Option<View<'_, T>> {
+        let frame = self.window_frame(top_left, width, height)?;
+        Some(View { grid: self, frame })
+    }
+
+    pub fn window_mut(
+        &mut self,
+        top_left: Pos,
+        width: usize,
+        height: usize,
+    ) -> Option<ViewMut<'_, T>> {
+        let frame = self.window_frame(top_left, width, height)?;
+        Some(ViewMut { grid: self, frame })
+    }
+
+    fn window_frame(&self, top_left: Pos, width: usize, height: usize) -> Option<Frame> {
+        let fits = self.contains(top_left)
+            && top_left.x as usize + width <= self.width
+            && top_left.y as usize + height <= self.height();
+        fits.then(|| {
+            Frame::new(
+                top_left.x as usize,
+                top_left.y as usize,
+                width,
+                height,
+                Transform::Identity,
+            )
+        })
+    }
+
+    /// `pos` moved onto the grid as if copies of it were laid out on every
+    /// side.
+    fn wrap(&self, pos: Pos) -> Pos {
+        Pos::new(
+            pos.x.rem_euclid(self.width as isize),
+            pos.y.rem_euclid(self.height() as isize),
+        )
+    }
+}
+
+impl<T: Clone> Faux2DArray<T> {
+    /// A copy of the grid turned or mirrored by `transform`.
+    pub fn transformed(&self, transform: Transform) -> Faux2DArray<T> {
+        self.view(transform).to_grid()
+    }
+
+    /// Rows become columns, mirroring the grid along its main diagonal.
+    pub fn transpose(&self) -> Faux2DArray<T> {
+        self.transformed(Transform::Transpose)
+    }
+
+    /// Rotated a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Faux2DArray<T> {
+        self.transformed(Transform::RotateCw)
+    }
+
+    /// Rotated a quarter turn counterclockwise.
+    pub fn rotate_ccw(&self) -> Faux2DArray<T> {
+        self.transformed(Transform::RotateCcw)
+    }
+
+    /// Mirrored left to right.
+    pub fn flip_h(&self) -> Faux2DArray<T> {
+        self.transformed(Transform::FlipH)
+    }
+
+    /// Mirrored top to bottom.
+    pub fn flip_v(&self) -> Faux2DArray<T> {
+        self.transformed(Transform::FlipV)
+    }
+}
+
+impl<T> Index<Pos> for Faux2DArray<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Faux2DArray<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseGridError {
+    Empty,
+    /// A row, counting from 0, that isn't as wide as the first one.
+    RaggedRow(usize),
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "Empty grid"),
+            ParseGridError::RaggedRow(row) => {
+                write!(f, "Row {} is not as wide as the first row", row)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
+impl FromStr for Faux2DArray<char> {
+    type Err = ParseGridError;
+
+    /// One item per character, one row per line.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let first = lines.next().ok_or(ParseGridError::Empty)?;
+        let mut grid = Faux2DArray::new(first.chars().count());
+        grid.items.extend(first.chars());
+        if grid.width == 0 {
+            return Err(ParseGridError::Empty);
+        }
+        for (row, line) in lines.enumerate() {
+            grid.add_row(line.chars().collect())
+                .map_err(|_| ParseGridError::RaggedRow(row + 1))?;
+        }
+        Ok(grid)
+    }
+}
+
+/// One of the eight ways to turn or mirror a grid onto itself.
+///
+/// Seen through a transform, an algorithm written for one direction works in
+/// all of them: tilting "up" in a `RotateCcw` view tilts the grid right, and
+/// scanning the rows of a `Transpose` view scans the grid's columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transform {
+    Identity,
+    /// A quarter turn clockwise.
+    RotateCw,
+    Rotate180,
+    /// A quarter turn counterclockwise.
+    RotateCcw,
+    /// Mirrored left to right.
+    FlipH,
+    /// Mirrored top to bottom.
+    FlipV,
+    /// Mirrored along the main diagonal, rows becoming columns.
+    Transpose,
+    /// Mirrored along the other diagonal.
+    AntiTranspose,
+}
+
+impl Transform {
+    pub const ALL: [Transform; 8] = [
+        Transform::Identity,
+        Transform::RotateCw,
+        Transform::Rotate180,
+        Transform::RotateCcw,
+        Transform::FlipH,
+        Transform::FlipV,
+        Transform::Transpose,
+        Transform::AntiTranspose,
+    ];
+
+    /// Whether the axes are swapped, then whether the x and y axes of the
+    /// original are reversed.
+    fn parts(self) -> (bool, bool, bool) {
+        match self {
+            Transform::Identity => (false, false, false),
+            Transform::RotateCw => (true, false, true),
+            Transform::Rotate180 => (false, true, true),
+            Transform::RotateCcw => (true, true, false),
+            Transform::FlipH => (false, true, false),
+            Transform::FlipV => (false, false, true),
+            Transform::Transpose => (true, false, false),
+            Transform::AntiTranspose => (true, true, true),
+        }
+    }
+
+    fn from_parts(parts: (bool, bool, bool)) -> Transform {
+        *Transform::ALL.iter().find(|t| t.parts() == parts).unwrap()
+    }
+
+    /// Whether the result is as wide as the original is high.
+    pub fn swaps_axes(self) -> bool {
+        self.parts().0
+    }
+
+    /// `self` applied first, then `next`, as one transform.
+    pub fn then(self, next: Transform) -> Transform {
+        let (swap, flip_x, flip_y) = self.parts();
+        let (next_swap, next_flip_x, next_flip_y) = next.parts();
+        // `next` flips axes of the already transformed grid, which are the
+        // original's the other way round if `self` swapped them
+        let (next_flip_x, next_flip_y) = if swap {
+            (next_flip_y, next_flip_x)
+        } else {
+            (next_flip_x, next_flip_y)
+        };
+        Transform::from_parts((
+            swap != next_swap,
+            flip_x != next_flip_x,
+            flip_y != next_flip_y,
+        ))
+    }
+}
+
+/// A rectangle of a grid and the transform it is seen through.
+#[derive(Debug, Clone, Copy)]
+struct Frame {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    transform: Transform,
+}
+
+impl Frame {
+    fn new(x: usize, y: usize, width: usize, height: usize, transform: Transform) -> Frame {
+        Frame {
+            x,
+            y,
+            width,
+            height,
+            transform,
+        }
+    }
+
+    /// Width and height as seen through the transform.
+    fn size(&self) -> (usize, usize) {
+        match self.transform.swaps_axes() {
+            true => (self.height, self.width),
+            false => (self.width, self.height),
+        }
+    }
+
+    fn contains(&self, pos: Pos) -> bool {
+        let (width, height) = self.size();
+        pos.x >= 0 && pos.y >= 0 && (pos.x as usize) < width && (pos.y as usize) < height
+    }
+
+    /// Where `pos` in the frame is on the underlying grid.
+    fn source(&self, pos: Pos) -> Option<(usize, usize)> {
+        if !self.contains(pos) {
+            return None;
+        }
+        let (swap, flip_x, flip_y) = self.transform.parts();
+        let (x, y) = match swap {
+            true => (pos.y as usize, pos.x as usize),
+            false => (pos.x as usize, pos.y as usize),
+        };
+        let x = if flip_x { self.width - 1 - x } else { x };
+        let y = if flip_y { self.height - 1 - y } else { y };
+        Some((self.x + x, self.y + y))
+    }
+
+    fn then(self, transform: Transform) -> Frame {
+        Frame {
+            transform: self.transform.then(transform),
+            ..self
+        }
+    }
+}
+
+/// A read-only, transformed or windowed look at a [`Faux2DArray`], see
+/// [`Faux2DArray::view`] and [`Faux2DArray::window`]. Indexed by [`Pos`]
+/// relative to the view, like the grid itself.
+pub struct View<'a, T> {
+    grid: &'a Faux2DArray<T>,
+    frame: Frame,
+}
+
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.frame.size().0
+    }
+
+    pub fn height(&self) -> usize {
+        self.frame.size().1
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.frame.contains(pos)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&'a T> {
+        let (x, y) = self.frame.source(pos)?;
+        self.grid.at(x, y)
+    }
+
+    /// This view further turned or mirrored by `transform`.
+    pub fn view(self, transform: Transform) -> View<'a, T> {
+        View {
+            frame: self.frame.then(transform),
+            ..self
+        }
+    }
+
+    pub fn row(self, y: usize) -> impl Iterator<Item = &'a T> {
+        (0..self.width()).map(move |x| self.get(Pos::from((x, y))).unwrap())
+    }
+
+    pub fn col(self, x: usize) -> impl Iterator<Item = &'a T> {
+        (0..self.height()).map(move |y| self.get(Pos::from((x, y))).unwrap())
+    }
+
+    pub fn rows(self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
+        (0..self.height()).map(move |y| self.row(y))
+    }
+
+    pub fn cols(self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
+        (0..self.width()).map(move |x| self.col(x))
+    }
+
+    /// Every item, row by row.
+    pub fn iter(self) -> impl Iterator<Item = &'a T> {
+        self.rows().flatten()
+    }
+
+    /// Every position, row by row.
+    pub fn positions(self) -> impl Iterator<Item = Pos> {
+        let width = self.width();
+        (0..width * self.height()).map(move |i| Pos::from((i % width, i / width)))
+    }
+
+    pub fn to_grid(self) -> Faux2DArray<T>
+    where
+        T: Clone,
+    {
+        Faux2DArray {
+            width: self.width(),
+            items: self.iter().cloned().collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for View<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the view", pos))
+    }
+}
+
+/// Like [`View`], but allows changing the items it covers.
+pub struct ViewMut<'a, T> {
+    grid: &'a mut Faux2DArray<T>,
+    frame: Frame,
+}
+
+impl<T> ViewMut<'_, T> {
+    pub fn width(&self) -> usize {
+        self.frame.size().0
+    }
+
+    pub fn height(&self) -> usize {
+        self.frame.size().1
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.frame.contains(pos)
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        let (x, y) = self.frame.source(pos)?;
+        Some(self.grid.absolute_index(x, y))
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.grid.items[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.grid.items[i])
+    }
+
+    /// Swaps the items at `a` and `b`. Panics if either is outside the view.
+    pub fn swap(&mut self, a: Pos, b: Pos) {
+        let (Some(a), Some(b)) = (self.index_of(a), self.index_of(b)) else {
+            panic!("{:?} or {:?} is outside the view", a, b);
+        };
+        self.grid.items.swap(a, b);
+    }
+
+    pub fn as_view(&self) -> View<'_, T> {
+        View {
+            grid: self.grid,
+            frame: self.frame,
+        }
+    }
+}
+
+impl<T> Index<Pos> for ViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the view", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for ViewMut<'_, T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the view", pos))
+    }
+}
+
+/// Access by [`Pos`] shared by [`Faux2DArray`], [`Toroidal`] and [`Tiled`],
+/// so a search written against it runs unchanged on any of them.
+pub trait Grid {
+    type Item;
+
+    /// The item at `pos`, if there is one.
+    fn get(&self, pos: Pos) -> Option<&Self::Item>;
+
+    fn contains(&self, pos: Pos) -> bool {
+        self.get(pos).is_some()
+    }
+
+    /// The position one step from `pos` in `dir`, if there is one.
+    fn step(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        let next = pos + dir;
+        self.contains(next).then_some(next)
+    }
+
+    /// The orthogonally adjacent positions.
+    fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The adjacent positions, diagonals included.
+    fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+}
+
+impl<T> Grid for Faux2DArray<T> {
+    type Item = T;
+
+    fn get(&self, pos: Pos) -> Option<&T> {
+        Faux2DArray::get(self, pos)
+    }
+
+    fn contains(&self, pos: Pos) -> bool {
+        Faux2DArray::contains(self, pos)
+    }
+}
+
+/// A [`Faux2DArray`] whose edges wrap around on both axes. Stepping off one
+/// edge lands on the opposite one, so positions always stay on the grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Toroidal<T> {
+    pub grid: Faux2DArray<T>,
+}
+
+impl<T> Toroidal<T> {
+    /// Panics if `grid` is empty.
+    pub fn new(grid: Faux2DArray<T>) -> Toroidal<T> {
+        assert!(!grid.items.is_empty(), "Cannot wrap an empty grid");
+        Toroidal { grid }
+    }
+
+    /// `pos` moved onto the grid.
+    pub fn wrap(&self, pos: Pos) -> Pos {
+        self.grid.wrap(pos)
+    }
+
+    /// Always `Some`, as every position wraps onto the grid. An `Option`
+    /// to match [`Faux2DArray::get_mut`] and [`Grid::get`].
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        let pos = self.wrap(pos);
+        self.grid.get_mut(pos)
+    }
+}
+
+impl<T> Grid for Toroidal<T> {
+    type Item = T;
+
+    fn get(&self, pos: Pos) -> Option<&T> {
+        self.grid.get(self.wrap(pos))
+    }
+
+    fn contains(&self, _pos: Pos) -> bool {
+        true
+    }
+
+    fn step(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        Some(self.wrap(pos + dir))
+    }
+}
+
+impl<T> Index<Pos> for Toroidal<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        &self.grid[self.wrap(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Toroidal<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let pos = self.wrap(pos);
+        &mut self.grid[pos]
+    }
+}
+
+/// An infinite plane tiled with copies of a [`Faux2DArray`], the original at
+/// tile (0, 0). Unlike [`Toroidal`], positions are not wrapped, so a search
+/// can tell the copies apart; [`Tiled::tile`] says which one a position is
+/// in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tiled<T> {
+    pub grid: Faux2DArray<T>,
+}
+
+impl<T> Tiled<T> {
+    /// Panics if `grid` is empty.
+    pub fn new(grid: Faux2DArray<T>) -> Tiled<T> {
+        assert!(!grid.items.is_empty(), "Cannot tile an empty grid");
+        Tiled { grid }
+    }
+
+    /// Where `pos` is within its tile.
+    pub fn wrap(&self, pos: Pos) -> Pos {
+        self.grid.wrap(pos)
+    }
+
+    /// The tile `pos` is in, counted in grid widths and heights from the
+    /// original.
+    pub fn tile(&self, pos: Pos) -> Pos {
+        Pos::new(
+            pos.x.div_euclid(self.grid.width as isize),
+            pos.y.div_euclid(self.grid.height() as isize),
+        )
+    }
+
+    /// The item at `pos`, changing it in every tile. Always `Some`, like
+    /// [`Toroidal::get_mut`].
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        let pos = self.wrap(pos);
+        self.grid.get_mut(pos)
+    }
+}
+
+impl<T> Grid for Tiled<T> {
+    type Item = T;
+
+    fn get(&self, pos: Pos) -> Option<&T> {
+        self.grid.get(self.wrap(pos))
+    }
+
+    fn contains(&self, _pos: Pos) -> bool {
+        true
+    }
+}
+
+impl<T> Index<Pos> for Tiled<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        &self.grid[self.wrap(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Tiled<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let pos = self.wrap(pos);
+        &mut self.grid[pos]
+    }
 }
 
 #[cfg(test)]
@@ -426,6 +1089,204 @@ mod tests {
         assert_eq!(result.as_slice(), target);
     }
 
+    #[test]
+    fn test_from_str() {
+        let grid: Faux2DArray<char> = "#.S\n.#.\n..E\n".parse().unwrap();
+        assert_eq!((grid.width, grid.height()), (3, 3));
+        assert_eq!(grid.find(|&c| c == 'S'), Some(Pos::new(2, 0)));
+        assert_eq!(grid.find(|&c| c == 'E'), Some(Pos::new(2, 2)));
+        assert_eq!(grid.find(|&c| c == 'X'), None);
+        assert_eq!(grid[Pos::new(1, 1)], '#');
+        assert_eq!("".parse::<Faux2DArray<char>>(), Err(ParseGridError::Empty));
+        assert_eq!(
+            "..\n...\n".parse::<Faux2DArray<char>>(),
+            Err(ParseGridError::RaggedRow(1))
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let mut a = create_usize_array_2();
+        assert_eq!(a.get(Pos::new(1, 2)), Some(&11));
+        assert_eq!(a.get(Pos::new(-1, 0)), None);
+        assert_eq!(a.get(Pos::new(0, 5)), None);
+        *a.get_mut(Pos::new(4, 4)).unwrap() = 99;
+        assert_eq!(a.at(4, 4), Some(&99));
+    }
+
+    #[test]
+    fn test_step() {
+        let a = create_usize_array_2();
+        let corner = Pos::new(0, 0);
+        assert_eq!(a.step(corner, Dir::Right), Some(Pos::new(1, 0)));
+        assert_eq!(a.step(corner, Dir::DownRight), Some(Pos::new(1, 1)));
+        assert_eq!(a.step(corner, Dir::Up), None);
+        assert_eq!(a.step(Pos::new(4, 4), Dir::Right), None);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let a = create_usize_array_2();
+        let values = |positions: Vec<Pos>| positions.into_iter().map(|p| a[p]).collect::<Vec<_>>();
+        assert_eq!(values(a.neighbors4(Pos::new(0, 0)).collect()), [1, 5]);
+        assert_eq!(
+            values(a.neighbors4(Pos::new(2, 2)).collect()),
+            [7, 13, 17, 11]
+        );
+        assert_eq!(values(a.neighbors8(Pos::new(0, 0)).collect()), [1, 6, 5]);
+        assert_eq!(a.neighbors8(Pos::new(2, 2)).count(), 8);
+    }
+
+    #[test]
+    fn test_dir() {
+        assert_eq!(Dir::Up.turn_right(), Dir::Right);
+        assert_eq!(Dir::Up.turn_left(), Dir::Left);
+        assert_eq!(Dir::UpLeft.turn_right(), Dir::UpRight);
+        assert_eq!(Dir::DownRight.opposite(), Dir::UpLeft);
+        assert_eq!(Dir::try_from('v'), Ok(Dir::Down));
+        assert_eq!(Pos::new(3, 3).step(Dir::DownLeft), Pos::new(2, 4));
+    }
+
+    fn parse(grid: &str) -> Faux2DArray<char> {
+        grid.parse().unwrap()
+    }
+
+    #[test]
+    fn test_transforms() {
+        let a = parse("abc\ndef\n");
+        assert_eq!(a.rotate_cw(), parse("da\neb\nfc\n"));
+        assert_eq!(a.rotate_ccw(), parse("cf\nbe\nad\n"));
+        assert_eq!(a.transpose(), parse("ad\nbe\ncf\n"));
+        assert_eq!(a.flip_h(), parse("cba\nfed\n"));
+        assert_eq!(a.flip_v(), parse("def\nabc\n"));
+        assert_eq!(a.transformed(Transform::Rotate180), parse("fed\ncba\n"));
+        assert_eq!(
+            a.transformed(Transform::AntiTranspose),
+            parse("fc\neb\nda\n")
+        );
+        assert_eq!(a.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), a);
+    }
+
+    #[test]
+    fn test_transform_then() {
+        let a = parse("abc\ndef\n");
+        for first in Transform::ALL {
+            for next in Transform::ALL {
+                assert_eq!(
+                    a.view(first).view(next).to_grid(),
+                    a.transformed(first).transformed(next),
+                    "{:?} then {:?}",
+                    first,
+                    next
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_view() {
+        let a = parse("abc\ndef\n");
+        let columns = a.view(Transform::Transpose);
+        assert_eq!((columns.width(), columns.height()), (2, 3));
+        assert_eq!(columns.row(1).collect::<String>(), "be");
+        assert_eq!(columns[Pos::new(1, 2)], 'f');
+        assert_eq!(columns.get(Pos::new(2, 0)), None);
+
+        let mut b = a.clone();
+        let mut right = b.view_mut(Transform::RotateCcw);
+        // Row 0 of a counterclockwise view is the grid's right column
+        right.swap(Pos::new(0, 0), Pos::new(1, 0));
+        assert_eq!(b, parse("abf\ndec\n"));
+    }
+
+    #[test]
+    fn test_window() {
+        let a = create_usize_array_2();
+        let window = a.window(Pos::new(1, 2), 3, 2).unwrap();
+        assert_eq!(window.to_grid().items, vec![11, 12, 13, 16, 17, 18]);
+        assert_eq!(window.get(Pos::new(3, 0)), None);
+        assert_eq!(
+            window.view(Transform::FlipH).row(0).collect::<Vec<_>>(),
+            [&13, &12, &11]
+        );
+        assert!(a.window(Pos::new(3, 0), 3, 1).is_none());
+        assert!(a.window(Pos::new(-1, 0), 1, 1).is_none());
+
+        let mut b = create_usize_array_2();
+        let mut window = b.window_mut(Pos::new(4, 4), 1, 1).unwrap();
+        window[Pos::new(0, 0)] = 0;
+        assert_eq!(b.at(4, 4), Some(&0));
+    }
+
+    #[test]
+    fn test_toroidal() {
+        let mut a = Toroidal::new(parse("abc\ndef\n"));
+        assert_eq!(a.step(Pos::new(0, 0), Dir::Up), Some(Pos::new(0, 1)));
+        assert_eq!(a.step(Pos::new(0, 0), Dir::UpLeft), Some(Pos::new(2, 1)));
+        assert_eq!(a.step(Pos::new(2, 1), Dir::Right), Some(Pos::new(0, 1)));
+        assert_eq!(a[Pos::new(-1, -1)], 'f');
+        assert_eq!(Grid::get(&a, Pos::new(4, 3)), Some(&'e'));
+        a[Pos::new(3, 2)] = 'x';
+        assert_eq!(a.grid.at(0, 0), Some(&'x'));
+    }
+
+    #[test]
+    fn test_tiled() {
+        let a = Tiled::new(parse("abc\ndef\n"));
+        assert_eq!(a.step(Pos::new(0, 0), Dir::UpLeft), Some(Pos::new(-1, -1)));
+        assert_eq!(a[Pos::new(-1, -1)], 'f');
+        assert_eq!(a.wrap(Pos::new(-1, -1)), Pos::new(2, 1));
+        assert_eq!(a.tile(Pos::new(-1, -1)), Pos::new(-1, -1));
+        assert_eq!(a.tile(Pos::new(5, 2)), Pos::new(1, 1));
+        assert_eq!(a.tile(Pos::new(2, 1)), Pos::new(0, 0));
+        assert_eq!(a.neighbors8(Pos::new(0, 0)).count(), 8);
+    }
+
+    #[test]
+    fn test_wrapped_get_mut() {
+        let mut a = Toroidal::new(parse("abc\ndef\n"));
+        *a.get_mut(Pos::new(-1, 0)).unwrap() = 'x';
+        assert_eq!(a.grid.at(2, 0), Some(&'x'));
+
+        let mut b = Tiled::new(parse("abc\ndef\n"));
+        *b.get_mut(Pos::new(4, -1)).unwrap() = 'y';
+        assert_eq!(b[Pos::new(1, 1)], 'y');
+        b[Pos::new(-3, 0)] = 'z';
+        assert_eq!(b.grid.at(0, 0), Some(&'z'));
+    }
+
+    /// Garden plots reachable in exactly `steps` steps, as in 2023 day 21.
+    fn reachable<G: Grid<Item = char>>(grid: &G, start: Pos, steps: usize) -> usize {
+        let mut positions = std::collections::HashSet::from([start]);
+        for _ in 0..steps {
+            positions = positions
+                .iter()
+                .flat_map(|&pos| grid.neighbors4(pos))
+                .filter(|&pos| grid.get(pos) != Some(&'#'))
+                .collect();
+        }
+        positions.len()
+    }
+
+    #[test]
+    fn test_grid_search() {
+        let garden = parse("...\n.#.\n...\n");
+        let start = Pos::new(0, 0);
+        assert_eq!(reachable(&garden, start, 2), 3);
+        assert_eq!(reachable(&Toroidal::new(garden.clone()), start, 2), 8);
+        assert_eq!(reachable(&Toroidal::new(garden.clone()), start, 9), 8);
+
+        // The tiled garden behaves like a big enough grid of copies
+        let copies = Faux2DArray::filled(27, 27, |x, y| garden.items[(y % 3) * 3 + x % 3]);
+        let tiled = Tiled::new(garden);
+        for steps in [2, 5, 9] {
+            assert_eq!(
+                reachable(&tiled, start, steps),
+                reachable(&copies, Pos::new(12, 12), steps)
+            );
+        }
+    }
+
     #[test]
     fn test_to_col_end() {
         let a = create_usize_array_2();
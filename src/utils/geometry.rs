@@ -99,3 +99,151 @@ impl Line {
         series
     }
 }
+
+/// A position on a grid, `x` growing to the right and `y` downwards like the
+/// rows of an input. Signed so that stepping off the top or left edge, or
+/// walking an unbounded grid, needs no special casing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Pos {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Pos { x, y }
+    }
+
+    /// The position one step away in `dir`.
+    pub fn step(self, dir: Dir) -> Pos {
+        self + dir
+    }
+
+    pub fn manhattan(self, other: Pos) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The four orthogonally adjacent positions.
+    pub fn neighbors4(self) -> impl Iterator<Item = Pos> {
+        Dir::ORTHOGONAL.into_iter().map(move |dir| self + dir)
+    }
+
+    /// All eight adjacent positions, diagonals included.
+    pub fn neighbors8(self) -> impl Iterator<Item = Pos> {
+        Dir::ALL.into_iter().map(move |dir| self + dir)
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from((x, y): (usize, usize)) -> Self {
+        Pos::new(x as isize, y as isize)
+    }
+}
+
+impl std::ops::Add for Pos {
+    type Output = Pos;
+
+    fn add(self, other: Pos) -> Pos {
+        Pos::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl std::ops::Sub for Pos {
+    type Output = Pos;
+
+    fn sub(self, other: Pos) -> Pos {
+        Pos::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl std::ops::Add<Dir> for Pos {
+    type Output = Pos;
+
+    fn add(self, dir: Dir) -> Pos {
+        self + dir.offset()
+    }
+}
+
+/// One of the eight directions on a grid, `Up` being towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir {
+    /// The four directions without diagonals, clockwise from `Up`.
+    pub const ORTHOGONAL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+    /// The four diagonal directions, clockwise from `UpRight`.
+    pub const DIAGONAL: [Dir; 4] = [Dir::UpRight, Dir::DownRight, Dir::DownLeft, Dir::UpLeft];
+    /// All eight directions, clockwise from `Up`.
+    pub const ALL: [Dir; 8] = [
+        Dir::Up,
+        Dir::UpRight,
+        Dir::Right,
+        Dir::DownRight,
+        Dir::Down,
+        Dir::DownLeft,
+        Dir::Left,
+        Dir::UpLeft,
+    ];
+
+    /// The change in position of one step this way.
+    pub const fn offset(self) -> Pos {
+        match self {
+            Dir::Up => Pos::new(0, -1),
+            Dir::UpRight => Pos::new(1, -1),
+            Dir::Right => Pos::new(1, 0),
+            Dir::DownRight => Pos::new(1, 1),
+            Dir::Down => Pos::new(0, 1),
+            Dir::DownLeft => Pos::new(-1, 1),
+            Dir::Left => Pos::new(-1, 0),
+            Dir::UpLeft => Pos::new(-1, -1),
+        }
+    }
+
+    /// Turned `eighths` of a full turn clockwise.
+    fn rotate(self, eighths: usize) -> Dir {
+        Dir::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Dir {
+        self.rotate(2)
+    }
+
+    /// A quarter turn counterclockwise.
+    pub fn turn_left(self) -> Dir {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Dir {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl TryFrom<char> for Dir {
+    type Error = char;
+
+    /// Reads the arrows and letters inputs use for directions: `^>v<`,
+    /// `URDL` and `NESW`.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'U' | 'N' => Ok(Dir::Up),
+            '>' | 'R' | 'E' => Ok(Dir::Right),
+            'v' | 'D' | 'S' => Ok(Dir::Down),
+            '<' | 'L' | 'W' => Ok(Dir::Left),
+            _ => Err(c),
+        }
+    }
+}
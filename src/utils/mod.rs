@@ -1,3 +1,5 @@
+pub mod bit_grid;
 pub mod collections;
 pub mod geometry;
 pub mod range;
+pub mod sparse_grid;
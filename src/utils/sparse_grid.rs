@@ -0,0 +1,272 @@
+use fxhash::FxHashMap;
+use std::ops::Index;
+
+use crate::utils::collections::Faux2DArray;
+
+/// The smallest rectangle holding a set of cells, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min_x: i64,
+    pub min_y: i64,
+    pub max_x: i64,
+    pub max_y: i64,
+}
+
+impl Bounds {
+    fn at((x, y): (i64, i64)) -> Bounds {
+        Bounds {
+            min_x: x,
+            min_y: y,
+            max_x: x,
+            max_y: y,
+        }
+    }
+
+    fn extend(&mut self, (x, y): (i64, i64)) {
+        self.min_x = self.min_x.min(x);
+        self.min_y = self.min_y.min(y);
+        self.max_x = self.max_x.max(x);
+        self.max_y = self.max_y.max(y);
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max_x - self.min_x) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max_y - self.min_y) as usize + 1
+    }
+
+    pub fn contains(&self, (x, y): (i64, i64)) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+}
+
+/// A grid without fixed size, only storing the cells that were set. For
+/// puzzles that walk or draw on an unbounded plane, `y` growing downwards
+/// like the rows of an input.
+///
+/// Tracks the bounding box of every cell inserted. Removing cells doesn't
+/// shrink it, see [`SparseGrid::shrink_bounds`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: FxHashMap<(i64, i64), T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: FxHashMap::default(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        Self::default()
+    }
+
+    /// Sets the cell at `pos`, returning what was there before.
+    pub fn insert(&mut self, pos: (i64, i64), value: T) -> Option<T> {
+        self.track(pos);
+        self.cells.insert(pos, value)
+    }
+
+    pub fn get(&self, pos: (i64, i64)) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: (i64, i64)) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    /// The cell at `pos`, set to `default()` first if it isn't yet.
+    pub fn get_or_insert_with<F>(&mut self, pos: (i64, i64), default: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        self.track(pos);
+        self.cells.entry(pos).or_insert_with(default)
+    }
+
+    pub fn remove(&mut self, pos: (i64, i64)) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn contains(&self, pos: (i64, i64)) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Number of cells set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The cells set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> + '_ {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// Number of cells set to a value matching `predicate`.
+    pub fn count<F>(&self, predicate: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.cells.values().filter(|value| predicate(value)).count()
+    }
+
+    /// The bounding box of every cell inserted so far, `None` before the
+    /// first one.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Shrinks the bounding box to the cells still set, after removing some.
+    pub fn shrink_bounds(&mut self) {
+        self.bounds = None;
+        let positions: Vec<(i64, i64)> = self.cells.keys().copied().collect();
+        for pos in positions {
+            self.track(pos);
+        }
+    }
+
+    fn track(&mut self, pos: (i64, i64)) {
+        match &mut self.bounds {
+            Some(bounds) => bounds.extend(pos),
+            None => self.bounds = Some(Bounds::at(pos)),
+        }
+    }
+
+    /// Draws the bounding box row by row, each cell (`None` where unset) as
+    /// the character `draw` picks for it. Every row ends with a newline.
+    pub fn render<F>(&self, draw: F) -> String
+    where
+        F: Fn(Option<&T>) -> char,
+    {
+        let Some(bounds) = self.bounds else {
+            return String::new();
+        };
+        let mut out = String::with_capacity((bounds.width() + 1) * bounds.height());
+        for y in bounds.min_y..=bounds.max_y {
+            out.extend((bounds.min_x..=bounds.max_x).map(|x| draw(self.get((x, y)))));
+            out.push('\n');
+        }
+        out
+    }
+
+    /// The bounding box as a dense grid, with `empty` in the cells that
+    /// aren't set, and the position its top left corner had here. `None` if
+    /// no cell was ever set.
+    pub fn to_grid(&self, empty: T) -> Option<(Faux2DArray<T>, (i64, i64))>
+    where
+        T: Clone,
+    {
+        let bounds = self.bounds?;
+        let grid = Faux2DArray::filled(bounds.width(), bounds.height(), |x, y| {
+            self.get((bounds.min_x + x as i64, bounds.min_y + y as i64))
+                .unwrap_or(&empty)
+                .clone()
+        });
+        Some((grid, (bounds.min_x, bounds.min_y)))
+    }
+
+    /// The cells of `grid` matching `keep`, e.g. only the walls of a maze,
+    /// with `grid`'s top left corner at (0, 0).
+    pub fn from_grid<F>(grid: Faux2DArray<T>, keep: F) -> SparseGrid<T>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let width = grid.width;
+        grid.items
+            .into_iter()
+            .enumerate()
+            .filter(|(_, value)| keep(value))
+            .map(|(i, value)| (((i % width) as i64, (i / width) as i64), value))
+            .collect()
+    }
+}
+
+impl<T> FromIterator<((i64, i64), T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = ((i64, i64), T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<((i64, i64), T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = ((i64, i64), T)>>(&mut self, iter: I) {
+        for (pos, value) in iter {
+            self.insert(pos, value);
+        }
+    }
+}
+
+impl<T> Index<(i64, i64)> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (i64, i64)) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is not set", pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds_and_render() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.render(|_| '.'), "");
+
+        grid.insert((2, -1), 'a');
+        grid.insert((-1, 1), 'b');
+        *grid.get_or_insert_with((0, 0), || 'x') = 'c';
+        let bounds = grid.bounds().unwrap();
+        assert_eq!(
+            (bounds.min_x, bounds.min_y, bounds.max_x, bounds.max_y),
+            (-1, -1, 2, 1)
+        );
+        assert_eq!((bounds.width(), bounds.height()), (4, 3));
+        assert_eq!(
+            grid.render(|cell| cell.copied().unwrap_or('.')),
+            "...a\n.c..\nb...\n"
+        );
+
+        grid.remove((2, -1));
+        assert_eq!(grid.bounds(), Some(bounds));
+        grid.shrink_bounds();
+        assert_eq!(grid.bounds().map(|b| (b.width(), b.height())), Some((2, 2)));
+    }
+
+    #[test]
+    fn test_count() {
+        let grid: SparseGrid<bool> = (0..10).map(|x| ((x, x), x % 3 == 0)).collect();
+        assert_eq!(grid.len(), 10);
+        assert_eq!(grid.count(|&lit| lit), 4);
+        assert!(grid[(3, 3)]);
+    }
+
+    #[test]
+    fn test_grid_conversion() {
+        let dense: Faux2DArray<char> = "#..\n.#.\n..#\n".parse().unwrap();
+        let mut sparse = SparseGrid::from_grid(dense.clone(), |&c| c == '#');
+        assert_eq!(sparse.len(), 3);
+        assert!(sparse.contains((1, 1)));
+        assert_eq!(sparse.to_grid('.'), Some((dense, (0, 0))));
+
+        sparse.insert((-1, 0), '#');
+        let (grid, origin) = sparse.to_grid('.').unwrap();
+        assert_eq!(origin, (-1, 0));
+        assert_eq!(grid, "##..\n..#.\n...#\n".parse().unwrap());
+        assert_eq!(SparseGrid::<char>::new().to_grid('.'), None);
+    }
+}
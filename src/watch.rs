@@ -0,0 +1,181 @@
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::answer::Answer;
+
+/// An answer asserted by one of a day's unit tests, e.g. `assert_eq!(result, 35)`
+/// after running part 1 on `inputs/2023/day05e.txt`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expectation {
+    /// File name of the input the test reads, e.g. `day05e.txt`.
+    pub input: String,
+    pub part: u8,
+    pub answer: Answer,
+}
+
+/// Collects the expected answers from the `#[test]` functions in a day's
+/// source. Tests that don't read an input file, don't call a
+/// `solution_*_NN` function or don't compare the result against a literal
+/// are skipped.
+pub fn expectations(source: &str) -> Vec<Expectation> {
+    let test_re = Regex::new(r"#\[test\]\s*(#\[ignore\]\s*)?fn \w+\(\)\s*\{").unwrap();
+    let path_re = Regex::new(r#""((?:[\w.-]+/)*([\w.-]+\.txt))""#).unwrap();
+    let call_re = Regex::new(r"solution_\d{4}_\d{2}_(\d{2})\s*\(").unwrap();
+    let assert_re = Regex::new(r"assert_eq!\(\s*(.+?)\s*,\s*(.+?)\s*\);").unwrap();
+
+    let starts: Vec<_> = test_re.find_iter(source).map(|m| m.start()).collect();
+    let mut expectations = Vec::new();
+    for (i, &start) in starts.iter().enumerate() {
+        let body = &source[start..starts.get(i + 1).copied().unwrap_or(source.len())];
+        let (Some(path), Some(call)) = (path_re.captures(body), call_re.captures(body)) else {
+            continue;
+        };
+        let Some(answer) = assert_re.captures_iter(body).find_map(|c| {
+            let is_result = |expr: &str| expr.contains("result") || expr.contains("solution_");
+            match (is_result(&c[1]), is_result(&c[2])) {
+                (true, false) => literal(&c[2]),
+                (false, true) => literal(&c[1]),
+                _ => None,
+            }
+        }) else {
+            continue;
+        };
+        expectations.push(Expectation {
+            input: path[2].to_string(),
+            part: call[1].parse().unwrap(),
+            answer,
+        });
+    }
+    expectations
+}
+
+/// Parses an integer or string literal, unwrapping `Some(..)` and `Ok(..)`.
+fn literal(expr: &str) -> Option<Answer> {
+    let expr = expr.trim();
+    for wrapper in ["Some(", "Ok("] {
+        if let Some(inner) = expr.strip_prefix(wrapper).and_then(|e| e.strip_suffix(')')) {
+            return literal(inner);
+        }
+    }
+    if let Some(s) = expr.strip_prefix('"').and_then(|e| e.strip_suffix('"')) {
+        return Some(Answer::from(s));
+    }
+    let digits = expr.trim_end_matches(|c: char| c.is_ascii_alphabetic() || c == '_');
+    digits
+        .replace('_', "")
+        .parse::<i128>()
+        .ok()
+        .map(Answer::from)
+}
+
+/// Source file of a day.
+pub fn source_path(year: u16, day: u16) -> PathBuf {
+    PathBuf::from(format!("src/solutions/year{}/day{:02}.rs", year, day))
+}
+
+/// Input files of a day, `dayNN.txt` as well as examples like `dayNNe.txt`,
+/// sorted so the examples come before the real input.
+pub fn input_paths(year: u16, day: u16) -> Vec<PathBuf> {
+    let prefix = format!("day{:02}", day);
+    let Ok(entries) = fs::read_dir(format!("inputs/{}", year)) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(".txt"))
+        })
+        .collect();
+    paths.sort_by_key(|p| (p.file_stem().is_some_and(|s| *s == *prefix), p.clone()));
+    paths
+}
+
+/// Modification times of everything `aoc watch` looks at for a day.
+pub fn snapshot(year: u16, day: u16) -> BTreeMap<PathBuf, SystemTime> {
+    std::iter::once(source_path(year, day))
+        .chain(input_paths(year, day))
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((path, modified))
+        })
+        .collect()
+}
+
+/// File name of `path`, for matching against [`Expectation::input`].
+pub fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+    #[test]
+    fn test_2022_05_01() {
+        let file_path: String = String::from("src/inputs/day05e.txt");
+        let result = solution_2022_05_01(file_path).unwrap();
+        assert_eq!(result, "CMZ");
+    }
+
+    #[test]
+    fn test_solution_2015_04_02() {
+        let file_path = String::from("inputs/2015/day04e2.txt");
+        assert_eq!(solution_2015_04_02(file_path), Some(609043));
+    }
+
+    #[test]
+    #[ignore]
+    fn output_2022_05_01() {
+        let file_path: String = String::from("inputs/2022/day05.txt");
+        let result = solution_2022_05_01(file_path);
+        dbg!(result.unwrap());
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    #[ignore]
+    fn output_2021_01_02() {
+        let file_path: String = String::from("inputs/2021/day01.txt");
+        let result = solution_2021_01_02(file_path).unwrap();
+        assert_eq!(result, 1_737);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("1,2"), vec![1, 2]);
+    }
+    "#;
+
+    #[test]
+    fn test_expectations() {
+        assert_eq!(
+            expectations(SOURCE),
+            vec![
+                Expectation {
+                    input: "day05e.txt".to_string(),
+                    part: 1,
+                    answer: Answer::from("CMZ"),
+                },
+                Expectation {
+                    input: "day04e2.txt".to_string(),
+                    part: 2,
+                    answer: Answer::Int(609043),
+                },
+                Expectation {
+                    input: "day01.txt".to_string(),
+                    part: 2,
+                    answer: Answer::Int(1737),
+                },
+            ]
+        );
+    }
+}